@@ -0,0 +1,150 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle.
+///
+/// Parts can return anything that converts into an answer, like the integer types or a `String`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture spanning several lines, like the letters drawn by the CRT of 2022's day 10.
+    Picture(String),
+}
+
+impl Answer {
+    /// The letters drawn in a picture, if every one of them is in the font that the puzzles use.
+    pub fn letters(&self) -> Option<String> {
+        match self {
+            Answer::Picture(picture) => read_letters(picture),
+            _ => None,
+        }
+    }
+
+    /// What to enter on the website for this answer, which for a picture are its letters.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Picture(_) => self.letters(),
+            answer => Some(answer.to_string()),
+        }
+    }
+
+    /// Whether this answer is the `expected` one, which for a picture can be given as its letters.
+    pub fn matches(&self, expected: &str) -> bool {
+        self.to_string().trim() == expected.trim()
+            || self
+                .letters()
+                .is_some_and(|letters| letters == expected.trim())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) | Answer::Picture(text) => f.write_str(text),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Answer {
+                Answer::Integer(value as i128)
+            }
+        })*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_owned())
+    }
+}
+
+/// The letters that the puzzles draw, four pixels wide and six pixels high.
+const FONT: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads the letters of a picture drawn with `#`, with a letter in every five columns.
+fn read_letters(picture: &str) -> Option<String> {
+    let rows: Vec<&[u8]> = picture.lines().map(str::as_bytes).collect();
+    let width = rows.first()?.len();
+    if rows.len() != 6 || rows.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    (0..width.div_ceil(5))
+        .map(|letter| {
+            let pixels: String = rows
+                .iter()
+                .flat_map(|row| (0..4).map(move |x| row.get(letter * 5 + x)))
+                .map(|pixel| if pixel == Some(&b'#') { '#' } else { '.' })
+                .collect();
+            FONT.iter()
+                .find(|(_, glyph)| *glyph == pixels)
+                .map(|&(c, _)| c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PICTURE: &str = "\
+###..####.#..#.
+#..#....#.#..#.
+#..#...#..####.
+###...#...#..#.
+#.#..#....#..#.
+#..#.####.#..#.";
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(95437_u32), Answer::Integer(95437));
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ"), Answer::Text(String::from("CMZ")));
+    }
+
+    #[test]
+    fn test_letters() {
+        let answer = Answer::Picture(PICTURE.to_owned());
+        assert_eq!(answer.letters().as_deref(), Some("RZH"));
+        assert_eq!(answer.submission().as_deref(), Some("RZH"));
+        assert!(answer.matches("RZH") && answer.matches(PICTURE) && !answer.matches("RZB"));
+
+        let answer = Answer::Picture(PICTURE.replacen('#', ".", 1));
+        assert_eq!(answer.letters(), None);
+        assert_eq!(answer.submission(), None);
+        assert_eq!(Answer::Integer(12).submission().as_deref(), Some("12"));
+    }
+}
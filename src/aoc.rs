@@ -0,0 +1,314 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::submit::Outcome;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides [`BASE_URL`], e.g. to try out the commands against a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Holds the session cookie. Takes precedence over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Holds the session cookie, relative to the home directory. Shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Identifies the template to the Advent of Code servers, as requested by its maintainers.
+pub const USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust (",
+    env!("CARGO_PKG_NAME"),
+    " ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum Error {
+    NoSession,
+    Status { url: String, status: u16 },
+    Transport(String),
+    UnexpectedResponse(String),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoSession => write!(
+                f,
+                "no session cookie found, set ${} or write it to ~/{}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            Error::Status { url, status } => {
+                write!(f, "request to {} failed with status {}", url, status)?;
+                match status {
+                    400 | 401 => write!(f, ", the session cookie is invalid or expired"),
+                    404 => write!(f, ", the puzzle is not unlocked yet"),
+                    _ => Ok(()),
+                }
+            }
+            Error::Transport(message) => write!(f, "request failed: {}", message),
+            Error::UnexpectedResponse(url) => {
+                write!(f, "could not make sense of the response from {}", url)
+            }
+            Error::Io { path, source } => {
+                write!(f, "could not write \"{}\": {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the session cookie from `$AOC_SESSION`, or from `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, Error> {
+    let session = match env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .and_then(|home| fs::read_to_string(Path::new(&home).join(SESSION_FILE)).ok())
+            .unwrap_or_default(),
+    };
+    match session.trim() {
+        "" => Err(Error::NoSession),
+        session => Ok(session.to_owned()),
+    }
+}
+
+/// Whether the file at `path` was downloaded before.
+/// `cargo scaffold` creates empty input files, so these don't count.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Download {
+    Fetched,
+    Cached,
+}
+
+/// Talks to the Advent of Code website on behalf of the user with the session cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client for adventofcode.com with the session cookie of the user.
+    pub fn from_env() -> Result<Client, Error> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(BASE_URL));
+        Ok(Client::new(&base_url, &read_session()?))
+    }
+
+    fn get(&self, path: &str) -> Result<String, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    /// Submits `answer` for `part` and returns how the server responded.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response(&url, response)?;
+        Outcome::parse(&html).ok_or(Error::UnexpectedResponse(url))
+    }
+
+    /// Fetches the puzzle description, including part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Downloads the input of `day` of `year` to `path`, unless it was downloaded before.
+    pub fn download_input(&self, year: u16, day: u8, path: &Path) -> Result<Download, Error> {
+        if is_cached(path) {
+            return Ok(Download::Cached);
+        }
+        let input = self.input(year, day)?;
+        write_atomic(path, &input).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Download::Fetched)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, Error> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| Error::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(Error::Status {
+            url: url.to_owned(),
+            status,
+        }),
+        Err(e) => Err(Error::Transport(e.to_string())),
+    }
+}
+
+/// Writes `contents` to a file next to `path` first, so that concurrent runs never see a partial file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(format!(".{}.tmp", process::id()));
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)
+}
+
+/// A stand-in for the Advent of Code server that answers requests with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one `(status, body)` response per connection, in order.
+    /// Returns the base URL and a handle that yields the raw requests once all responses were sent.
+    pub fn serve(responses: &[(u16, &str)]) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<(u16, String)> = responses
+            .iter()
+            .map(|(status, body)| (*status, body.to_string()))
+            .collect();
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut content = vec![0; content_length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("aoc-{}-{}", name, process::id()))
+            .join("07.txt")
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = mock::serve(&[(200, "1000\n2000\n")]);
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(client.input(2022, 7).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/7/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("\r\nUser-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_input_status() {
+        let (base_url, _server) = mock::serve(&[(404, "Not Found")]);
+        let client = Client::new(&base_url, "abc123");
+        let e = client.input(2022, 25).unwrap_err();
+        assert!(matches!(e, Error::Status { status: 404, .. }));
+        assert!(e.to_string().contains("not unlocked"));
+    }
+
+    #[test]
+    fn test_download_input_is_cached() {
+        let path = temp_path("download");
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+
+        // The server answers a single request, so a second fetch would fail.
+        let (base_url, server) = mock::serve(&[(200, "1000\n")]);
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(
+            client.download_input(2022, 7, &path).unwrap(),
+            Download::Fetched
+        );
+        assert_eq!(
+            client.download_input(2022, 7, &path).unwrap(),
+            Download::Cached
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n");
+        assert_eq!(server.join().unwrap().len(), 1);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,278 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use crate::report::Format;
+use crate::ReadError;
+
+/// Arguments shared by the aggregate runner and the per-day binaries.
+pub struct Args {
+    pub format: Format,
+    pub bench: Option<usize>,
+    pub save_baseline: bool,
+}
+
+impl Args {
+    /// Takes the shared arguments out of `args`, leaving the rest to the caller.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+        let parsed = Args {
+            format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+            bench: args.opt_value_from_fn("--bench", parse_runs)?,
+            save_baseline: args.contains("--save-baseline"),
+        };
+        if parsed.save_baseline && parsed.bench.is_none() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: String::from("--save-baseline requires --bench"),
+            });
+        }
+        Ok(parsed)
+    }
+}
+
+/// Where a per-day binary reads its input from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `inputs/YYYY/NN.txt`.
+    Puzzle,
+    /// The example in `examples/YYYY/NN.txt`.
+    Example,
+    /// Any file given with `--input`, or stdin for `-`.
+    Path(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self, year: u16, day: u8) -> Result<String, ReadError> {
+        match self {
+            InputSource::Puzzle => crate::read_file("inputs", year, day),
+            InputSource::Example => crate::read_file("examples", year, day),
+            InputSource::Path(path) => crate::read_path(path),
+        }
+    }
+
+    /// Stored answers and baselines only apply to the puzzle input.
+    pub fn is_puzzle(&self) -> bool {
+        *self == InputSource::Puzzle
+    }
+}
+
+/// Arguments of the per-day binaries.
+pub struct DayArgs {
+    pub args: Args,
+    pub input: InputSource,
+}
+
+impl DayArgs {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<DayArgs, pico_args::Error> {
+        let shared = Args::parse(args)?;
+        let example = args.contains("--example");
+        let input = match (args.opt_value_from_str("--input")?, example) {
+            (None, false) => InputSource::Puzzle,
+            (None, true) => InputSource::Example,
+            (Some(path), false) => InputSource::Path(path),
+            (Some(_), true) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: String::from("--input and --example can't be used together"),
+                })
+            }
+        };
+        if shared.save_baseline && !input.is_puzzle() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: String::from("--save-baseline requires the puzzle input"),
+            });
+        }
+        Ok(DayArgs {
+            args: shared,
+            input,
+        })
+    }
+}
+
+/// Parses the process arguments with `parse` and rejects any leftovers.
+/// Exits with an error message if the arguments are invalid.
+pub fn parse_or_exit<T, P>(parse: P) -> T
+where
+    P: FnOnce(&mut pico_args::Arguments) -> Result<T, pico_args::Error>,
+{
+    // `cargo solve 2022 7 -- --format json` passes the separator on to the binary.
+    let args = env::args_os().skip(1).filter(|arg| arg != "--").collect();
+    let mut args = pico_args::Arguments::from_vec(args);
+    let parsed = parse(&mut args).and_then(|parsed| {
+        let remaining = args.finish();
+        if remaining.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unexpected arguments {:?}", remaining),
+            })
+        }
+    });
+
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+pub fn parse_args() -> DayArgs {
+    parse_or_exit(DayArgs::parse)
+}
+
+/// Parses a day selection such as `1,7,13-15` into a sorted list of days.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for item in s.split(',').map(str::trim) {
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+            None => {
+                let day = parse_day(item)?;
+                (day, day)
+            }
+        };
+        if start > end {
+            return Err(format!("invalid range '{}', start is after end", item));
+        }
+        days.extend(start..=end);
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Takes the free `[year] day` arguments out of `args`, e.g. `2022 7` or just `7`.
+/// The year defaults to the most recent year with registered solutions.
+/// Call this after all options have been taken.
+pub fn parse_puzzle(args: &mut pico_args::Arguments) -> Result<(u16, u8), pico_args::Error> {
+    let first: String = args.free_from_str()?;
+    let (year, day) = match args.opt_free_from_fn(parse_day)? {
+        Some(day) => (parse_year(&first), day),
+        None => {
+            let year = crate::latest_year().ok_or_else(|| String::from("no year given"));
+            (
+                year,
+                parse_day(&first).map_err(|cause| failed(&first, cause))?,
+            )
+        }
+    };
+    Ok((year.map_err(|cause| failed(&first, cause))?, day))
+}
+
+fn failed(value: &str, cause: String) -> pico_args::Error {
+    pico_args::Error::Utf8ArgumentParsingFailed {
+        value: value.to_owned(),
+        cause,
+    }
+}
+
+pub fn parse_year(s: &str) -> Result<u16, String> {
+    match s.trim().parse() {
+        Ok(year @ 2015..) => Ok(year),
+        _ => Err(format!(
+            "invalid year '{}', expected a year from 2015 onwards",
+            s
+        )),
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!(
+            "invalid day '{}', expected a number from 1 to 25",
+            s
+        )),
+    }
+}
+
+/// Parses the number of runs of `--bench`.
+pub fn parse_runs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(runs @ 1..) => Ok(runs),
+        _ => Err(format!(
+            "invalid number of runs '{}', expected at least 1",
+            s
+        )),
+    }
+}
+
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_day_args(args: &[&str]) -> Result<DayArgs, pico_args::Error> {
+        let args = args.iter().map(Into::into).collect();
+        DayArgs::parse(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn test_parse_day_args() {
+        let parsed = parse_day_args(&[]).unwrap();
+        assert_eq!(parsed.input, InputSource::Puzzle);
+
+        let parsed = parse_day_args(&["--example"]).unwrap();
+        assert_eq!(parsed.input, InputSource::Example);
+
+        let parsed = parse_day_args(&["--input", "edge.txt", "--format", "json"]).unwrap();
+        assert_eq!(parsed.input, InputSource::Path(PathBuf::from("edge.txt")));
+        assert_eq!(parsed.args.format, Format::Json);
+
+        assert!(parse_day_args(&["--input", "-", "--example"]).is_err());
+        assert!(parse_day_args(&["--example", "--bench", "5", "--save-baseline"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("1,7,13-15"), Ok(vec![1, 7, 13, 14, 15]));
+        assert_eq!(parse_days("3-5, 4,1"), Ok(vec![1, 3, 4, 5]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("1,,2").is_err());
+        assert!(parse_days("a-b").is_err());
+    }
+
+    fn parse_puzzle_args(args: &[&str]) -> Result<(u16, u8), pico_args::Error> {
+        let args = args.iter().map(Into::into).collect();
+        parse_puzzle(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn test_parse_puzzle() {
+        assert_eq!(parse_puzzle_args(&["2021", "07"]).unwrap(), (2021, 7));
+        let latest = crate::latest_year().unwrap();
+        assert_eq!(parse_puzzle_args(&["7"]).unwrap(), (latest, 7));
+        assert!(parse_puzzle_args(&[]).is_err());
+        assert!(parse_puzzle_args(&["26"]).is_err());
+        assert!(parse_puzzle_args(&["1999", "7"]).is_err());
+        assert!(parse_puzzle_args(&["2022", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("1"), Ok(1));
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_parse_runs() {
+        assert_eq!(parse_runs("100"), Ok(100));
+        assert!(parse_runs("0").is_err());
+        assert!(parse_runs("-1").is_err());
+        assert!(parse_day_args(&["--bench", "0"]).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::report::Record;
+
+/// Where `--save-baseline` stores the benchmark results of each day, relative to the crate root.
+pub const BASELINE_DIR: &str = "target/bench";
+
+/// Summary statistics over repeated runs of one part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty());
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// Relative change of the median compared to `baseline`, in percent.
+    pub fn change(&self, baseline: &Stats) -> f64 {
+        (self.median.as_secs_f64() / baseline.median.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+/// Runs `func` `runs` times after a short warmup and returns the answer of the last run.
+/// `runs` must be at least 1, see [`crate::args::parse_runs`].
+pub fn run<T>(func: impl Fn(&str) -> T, input: &str, runs: usize) -> (T, Stats) {
+    assert!(runs > 0, "a benchmark needs at least one run");
+    for _ in 0..(runs / 10).max(1) {
+        func(input);
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let timer = Instant::now();
+        result = Some(func(input));
+        samples.push(timer.elapsed());
+    }
+
+    (result.unwrap(), Stats::from_samples(&samples))
+}
+
+/// Benchmark results of a day, keyed by part.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    parts: BTreeMap<u8, Stats>,
+}
+
+impl Baseline {
+    fn path(year: u16, day: u8) -> PathBuf {
+        crate::project_path(BASELINE_DIR)
+            .join(year.to_string())
+            .join(format!("{:02}.json", day))
+    }
+
+    /// Loads the saved baseline for `day`, if there is one.
+    pub fn load(year: u16, day: u8) -> Option<Baseline> {
+        let s = fs::read_to_string(Baseline::path(year, day)).ok()?;
+        serde_json::from_str(&s).ok()
+    }
+
+    /// Replaces the stats of every benchmarked part in `records`.
+    pub fn update(&mut self, records: &[Record]) {
+        for record in records {
+            if let Some(stats) = record.bench {
+                self.parts.insert(record.part, stats);
+            }
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = Baseline::path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Stats> {
+        self.parts.get(&part)
+    }
+}
+
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 8, 6]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev.as_nanos(), 2236);
+
+        let stats = Stats::from_samples(&micros(&[3, 1, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2));
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Stats::from_samples(&micros(&[200]));
+        let faster = Stats::from_samples(&micros(&[150]));
+        assert!((faster.change(&baseline) + 25_f64).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_run() {
+        let (result, stats) = run(|input| Some(input.len()), "abc", 5);
+        assert_eq!(result, Some(3));
+        assert_eq!(stats.runs, 5);
+    }
+
+    #[test]
+    fn test_baseline_json() {
+        let baseline = Baseline {
+            parts: BTreeMap::from([(1, Stats::from_samples(&micros(&[1, 3])))]),
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"parts":{"1":{"runs":2,"min":1000,"median":2000,"mean":2000,"stddev":1000}}}"#
+        );
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code::y2022::d06::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(2022, 6, part_one, part_two);
+}
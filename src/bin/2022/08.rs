@@ -0,0 +1,5 @@
+use advent_of_code::y2022::d08::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(2022, 8, part_one, part_two);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::y2022::d11::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(2022, 11, part_one, part_two);
+}
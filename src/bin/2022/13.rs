@@ -0,0 +1,5 @@
+use advent_of_code::y2022::d13::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(2022, 13, part_one, part_two);
+}
@@ -0,0 +1,5 @@
+use advent_of_code::y2022::d14::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(2022, 14, part_one, part_two);
+}
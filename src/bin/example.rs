@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::puzzle::{self, Puzzle};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+/// Where downloaded puzzle descriptions are kept, relative to the crate root.
+const PUZZLE_DIR: &str = "target/puzzles";
+
+struct Args {
+    year: u16,
+    day: u8,
+    html: Option<PathBuf>,
+    block: usize,
+}
+
+fn parse_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+    let html = args.opt_value_from_str("--html")?;
+    let block = args.opt_value_from_str("--block")?.unwrap_or(1);
+    let (year, day) = advent_of_code::args::parse_puzzle(args)?;
+    Ok(Args {
+        year,
+        day,
+        html,
+        block,
+    })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Reads the puzzle page, downloading it unless a complete copy was downloaded before.
+fn read_puzzle(year: u16, day: u8) -> Result<String, String> {
+    let path = advent_of_code::project_path(PUZZLE_DIR)
+        .join(year.to_string())
+        .join(format!("{:02}.html", day));
+    if let Ok(html) = fs::read_to_string(&path) {
+        if Puzzle::is_complete(&html) {
+            return Ok(html);
+        }
+    }
+
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    println!("Downloading puzzle for day {}, {}...", day, year);
+    let html = client.puzzle(year, day).map_err(|e| e.to_string())?;
+    if let Err(e) = aoc::write_atomic(&path, &html) {
+        eprintln!("Failed to cache puzzle: {}", e);
+    }
+    Ok(html)
+}
+
+fn main() {
+    let args = advent_of_code::args::parse_or_exit(parse_args);
+
+    let html = match &args.html {
+        Some(path) => advent_of_code::read_path(path).map_err(|e| e.to_string()),
+        None => read_puzzle(args.year, args.day),
+    };
+    let puzzle = match html {
+        Ok(html) => Puzzle::parse(&html),
+        Err(e) => exit_with_error(&format!("Failed to read puzzle: {}", e)),
+    };
+
+    let example_path = advent_of_code::file_path("examples", args.year, args.day);
+    match puzzle.examples.get(args.block.wrapping_sub(1)) {
+        Some(_) if aoc::is_cached(&example_path) => println!(
+            "Example file \"{}\" is not empty, leaving it alone.",
+            example_path.display()
+        ),
+        Some(example) => match aoc::write_atomic(&example_path, example) {
+            Ok(_) => println!("Wrote example to \"{}\"", example_path.display()),
+            Err(e) => exit_with_error(&format!("Failed to write example file: {}", e)),
+        },
+        None => exit_with_error(&format!(
+            "The puzzle has {} example blocks, can't use block {}.",
+            puzzle.examples.len(),
+            args.block
+        )),
+    }
+
+    let module_path = advent_of_code::project_path("src")
+        .join(format!("y{}", args.year))
+        .join(format!("d{:02}.rs", args.day));
+    let module = match fs::read_to_string(&module_path) {
+        Ok(module) => module,
+        Err(e) => exit_with_error(&format!(
+            "Failed to read module file \"{}\": {}",
+            module_path.display(),
+            e
+        )),
+    };
+    let filled = puzzle::fill_asserts(&module, &puzzle.answers);
+    let changed = filled != module;
+    if changed {
+        if let Err(e) = fs::write(&module_path, filled) {
+            exit_with_error(&format!("Failed to write module file: {}", e));
+        }
+    }
+    for (part, answer) in puzzle.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            println!("Expected answer for part {}: {}", part + 1, answer);
+        }
+    }
+
+    println!("---");
+    if changed {
+        println!(
+            "🎄 Filled in the tests of \"{}\". Check that they match the puzzle!",
+            module_path.display()
+        );
+    } else {
+        println!(
+            "🎄 Found no placeholder tests to fill in in \"{}\".",
+            module_path.display()
+        );
+    }
+}
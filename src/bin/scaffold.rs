@@ -3,70 +3,329 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
+    str::FromStr,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const BIN_TEMPLATE: &str = r###"use advent_of_code::YEAR_MODULE::DAY_MODULE::{part_one, part_two};
+
+fn main() {
+    advent_of_code::solve!(YEAR, DAY, part_one, part_two);
 }
+"###;
+
+const BIN_ENTRY_TEMPLATE: &str = r###"
+[[bin]]
+name = "NAME"
+path = "PATH"
+"###;
+
+const REGISTRY_TEMPLATE: &str = r###"/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every solution in this directory with the runner.
+ */
+use crate::Day;
+
+MODULES
+
+pub const DAYS: &[Day] = &[
+ENTRIES
+];
+"###;
+
+const YEARS_TEMPLATE: &str = r###"/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every year of solutions with the runner.
+ */
+use crate::Day;
+
+MODULES
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+#[rustfmt::skip]
+pub const YEARS: &[(u16, &[Day])] = &[
+ENTRIES
+];
+"###;
+
+/// The aliases that the commands of the template rely on.
+const ALIASES: &[(&str, &str)] = &[
+    ("scaffold", "run --bin scaffold -- "),
+    ("download", "run --bin download -- "),
+    ("example", "run --bin example -- "),
+    ("submit", "run --release --bin submit -- "),
+    ("solve", "run --bin solve"),
+    ("all", "run --release -- "),
+];
+
+// relative to the crate root, see `advent_of_code::project_path`.
+const SRC_DIR: &str = "src";
+const BIN_DIR: &str = "src/bin";
+const YEARS_PATH: &str = "src/years.rs";
+const MANIFEST_PATH: &str = "Cargo.toml";
+const CONFIG_PATH: &str = ".cargo/config";
+const TEMPLATES_DIR: &str = "templates";
+
+struct Args {
+    puzzle: Option<(u16, u8)>,
+    template: String,
+    answer: String,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+    let template = args
+        .opt_value_from_str("--template")?
+        .unwrap_or_else(|| String::from("default"));
+    let answer = args
+        .opt_value_from_str("--answer")?
+        .unwrap_or_else(|| String::from("u32"));
+    let puzzle = if args.contains("--sync") {
+        None
+    } else {
+        let puzzle = advent_of_code::args::parse_puzzle(args).map_err(|e| match e {
+            pico_args::Error::MissingArgument => pico_args::Error::ArgumentParsingFailed {
+                cause: String::from("need to specify a day (as integer), optionally preceded by a year. example: `cargo scaffold 2022 7`"),
+            },
+            e => e,
+        })?;
+        Some(puzzle)
+    };
+    Ok(Args {
+        puzzle,
+        template,
+        answer,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
     }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+/// Fills in the `{{DAY}}`, `{{YEAR}}` and `{{ANSWER}}` placeholders of a module template.
+fn render_template(template: &str, year: u16, day: u8, answer: &str) -> String {
+    template
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{ANSWER}}", answer)
+}
+
+/// Lists the numbers `n` of the entries in `dir` named `{prefix}{n}{suffix}`, in ascending order.
+fn numbered_entries<T: FromStr + Ord>(dir: &Path, prefix: &str, suffix: &str) -> Vec<T> {
+    let mut numbers: Vec<T> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()
+        })
+        .collect();
+    numbers.sort();
+    numbers
+}
+
+/// Rewrites the registry of `year` so that it lists every `dNN.rs` module in `src/yYYYY`.
+fn write_registry(year: u16) -> Result<(), std::io::Error> {
+    let dir = advent_of_code::project_path(SRC_DIR).join(format!("y{}", year));
+    let days: Vec<u8> = numbered_entries(&dir, "d", ".rs");
+
+    let modules: String = days
+        .iter()
+        .map(|day| format!("pub mod d{:02};\n", day))
+        .collect();
+    let entries: String = days
+        .iter()
+        .map(|day| format!("    day!({}, {}, d{:02}),\n", year, day, day))
+        .collect();
+
+    fs::write(
+        dir.join("mod.rs"),
+        REGISTRY_TEMPLATE
+            .replace("MODULES\n", &modules)
+            .replace("ENTRIES\n", &entries),
+    )
+}
+
+/// Rewrites `src/years.rs` so that it lists every `yYYYY` directory in `src`.
+fn write_years() -> Result<(), std::io::Error> {
+    let years: Vec<u16> = numbered_entries(&advent_of_code::project_path(SRC_DIR), "y", "");
+
+    let modules: String = years
+        .iter()
+        .map(|year| format!("#[path = \"y{0}/mod.rs\"]\npub mod y{0};\n", year))
+        .collect();
+    let entries: String = years
+        .iter()
+        .map(|year| format!("    ({0}, y{0}::DAYS),\n", year))
+        .collect();
+
+    fs::write(
+        advent_of_code::project_path(YEARS_PATH),
+        YEARS_TEMPLATE
+            .replace("MODULES\n", &modules)
+            .replace("ENTRIES\n", &entries),
+    )
+}
+
+/// Binaries in subdirectories of `src/bin` are not discovered by cargo and need an entry in the manifest.
+/// Replaces the entries of the per-day binaries in `manifest` with one for each of `bins`.
+fn sync_bins(manifest: &str, bins: &[(u16, u8)]) -> String {
+    let is_day_bin = |section: &str| {
+        section.starts_with("[[bin]]")
+            && section.lines().any(|line| {
+                line.strip_prefix("path = \"src/bin/")
+                    .and_then(|path| path.split_once('/'))
+                    .is_some_and(|(year, _)| year.parse::<u16>().is_ok())
+            })
+    };
+
+    let mut synced = String::new();
+    let mut section = String::new();
+    for line in manifest.lines() {
+        if line.starts_with('[') {
+            if !is_day_bin(&section) {
+                synced.push_str(&section);
+            }
+            section.clear();
+        }
+        section.push_str(line);
+        section.push('\n');
+    }
+    if !is_day_bin(&section) {
+        synced.push_str(&section);
+    }
+
+    let mut synced = synced.trim_end().to_owned();
+    synced.push('\n');
+    for (year, day) in bins {
+        synced.push_str(
+            &BIN_ENTRY_TEMPLATE
+                .replace("NAME", &format!("{}-{:02}", year, day))
+                .replace("PATH", &format!("{}/{}/{:02}.rs", BIN_DIR, year, day)),
+        );
     }
+    synced
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Adds the aliases from [`ALIASES`] that are missing in `config`. Existing aliases are left alone.
+fn sync_aliases(config: &str) -> String {
+    let missing: String = ALIASES
+        .iter()
+        .filter(|(name, _)| {
+            !config
+                .lines()
+                .filter_map(|line| line.split_once('='))
+                .any(|(key, _)| key.trim() == *name)
+        })
+        .map(|(name, command)| format!("{} = \"{}\"\n", name, command))
+        .collect();
+    match config.split_once("[alias]\n") {
+        Some((before, after)) => format!("{}[alias]\n{}{}", before, missing, after),
+        None if config.trim().is_empty() => format!("[alias]\n{}", missing),
+        None => format!("{}\n\n[alias]\n{}", config.trim_end(), missing),
+    }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Rewrites `path` with `sync` applied to its contents. Returns whether it changed.
+fn sync_file(path: &Path, sync: impl FnOnce(&str) -> String) -> Result<bool, std::io::Error> {
+    let contents = fs::read_to_string(path).unwrap_or_default();
+    let synced = sync(&contents);
+    if synced == contents {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, synced)?;
+    Ok(true)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Brings the registries, the manifest and the aliases in line with the solutions on disk.
+fn sync() -> Result<(), std::io::Error> {
+    for year in numbered_entries::<u16>(&advent_of_code::project_path(SRC_DIR), "y", "") {
+        write_registry(year)?;
+    }
+    write_years()?;
+
+    let bin_dir = advent_of_code::project_path(BIN_DIR);
+    let bins: Vec<(u16, u8)> = numbered_entries::<u16>(&bin_dir, "", "")
+        .into_iter()
+        .flat_map(|year| {
+            numbered_entries::<u8>(&bin_dir.join(year.to_string()), "", ".rs")
+                .into_iter()
+                .map(move |day| (year, day))
+        })
+        .collect();
+    let manifest_path = advent_of_code::project_path(MANIFEST_PATH);
+    if sync_file(&manifest_path, |manifest| sync_bins(manifest, &bins))? {
+        println!("Synced binaries in \"{}\"", manifest_path.display());
+    }
+    let config_path = advent_of_code::project_path(CONFIG_PATH);
+    if sync_file(&config_path, sync_aliases)? {
+        println!("Added missing aliases to \"{}\"", config_path.display());
+    }
+    Ok(())
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+    let args = advent_of_code::args::parse_or_exit(parse_args);
+
+    let (year, day) = match args.puzzle {
+        Some(puzzle) => puzzle,
+        None => {
+            if let Err(e) = sync() {
+                eprintln!("Failed to sync: {}", e);
+                process::exit(1);
+            }
+            println!("🎄 Registries, binaries and aliases are in sync.");
+            return;
+        }
+    };
+
+    let template_path =
+        advent_of_code::project_path(TEMPLATES_DIR).join(format!("{}.tpl", args.template));
+    let template = match fs::read_to_string(&template_path) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!(
+                "Failed to read template \"{}\": {}",
+                template_path.display(),
+                e
+            );
             process::exit(1);
         }
     };
 
     let day_padded = format!("{:02}", day);
+    let year_module = format!("y{}", year);
+    let day_module = format!("d{}", day_padded);
+
+    let input_path = advent_of_code::file_path("inputs", year, day);
+    let example_path = advent_of_code::file_path("examples", year, day);
+    let module_dir = advent_of_code::project_path(SRC_DIR).join(&year_module);
+    let module_path = module_dir.join(format!("{}.rs", day_module));
+    let bin_dir = advent_of_code::project_path(BIN_DIR).join(year.to_string());
+    let bin_path = bin_dir.join(format!("{}.rs", day_padded));
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    if let Err(e) = fs::create_dir_all(&module_dir) {
+        eprintln!("Failed to create module directory: {}", e);
+        process::exit(1);
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +335,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(render_template(&template, year, day, &args.answer).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,9 +345,52 @@ fn main() {
         }
     }
 
+    if let Err(e) = fs::create_dir_all(&bin_dir) {
+        eprintln!("Failed to create binary directory: {}", e);
+        process::exit(1);
+    }
+
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(
+        BIN_TEMPLATE
+            .replace("YEAR_MODULE", &year_module)
+            .replace("DAY_MODULE", &day_module)
+            .replace("YEAR", &year.to_string())
+            .replace("DAY", &day.to_string())
+            .as_bytes(),
+    ) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", bin_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match sync() {
+        Ok(_) => {
+            println!(
+                "Registered day in \"{}\"",
+                module_dir.join("mod.rs").display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to register day: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +400,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -108,7 +410,53 @@ fn main() {
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} {}` to run your solution.",
+        year, &day_padded
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let template = "fn part_one(input: &str) -> Option<{{ANSWER}}>\nread_file(\"examples\", {{YEAR}}, {{DAY}})\n";
+        assert_eq!(
+            render_template(template, 2022, 7, "u64"),
+            "fn part_one(input: &str) -> Option<u64>\nread_file(\"examples\", 2022, 7)\n"
+        );
+    }
+
+    #[test]
+    fn test_sync_bins() {
+        let manifest = "[package]\nname = \"advent_of_code\"\n\n\
+                        [dependencies]\nregex = \"1\"\n\n\
+                        [[bin]]\nname = \"2022-01\"\npath = \"src/bin/2022/01.rs\"\n\n\
+                        [[bin]]\nname = \"tool\"\npath = \"src/bin/tools/tool.rs\"\n\n\
+                        [[bin]]\nname = \"2022-02\"\npath = \"src/bin/2022/02.rs\"\n";
+        assert_eq!(
+            sync_bins(manifest, &[(2021, 3), (2022, 1)]),
+            "[package]\nname = \"advent_of_code\"\n\n\
+             [dependencies]\nregex = \"1\"\n\n\
+             [[bin]]\nname = \"tool\"\npath = \"src/bin/tools/tool.rs\"\n\n\
+             [[bin]]\nname = \"2021-03\"\npath = \"src/bin/2021/03.rs\"\n\n\
+             [[bin]]\nname = \"2022-01\"\npath = \"src/bin/2022/01.rs\"\n"
+        );
+
+        let synced = sync_bins(manifest, &[(2022, 1), (2022, 2)]);
+        assert_eq!(sync_bins(&synced, &[(2022, 1), (2022, 2)]), synced);
+    }
+
+    #[test]
+    fn test_sync_aliases() {
+        let config = "[alias]\nsolve = \"run --release --bin solve\"\nall = \"run\"\n";
+        let synced = sync_aliases(config);
+        assert!(synced.starts_with("[alias]\nscaffold = \"run --bin scaffold -- \"\n"));
+        assert!(synced.contains("solve = \"run --release --bin solve\"\n"));
+        assert!(!synced.contains("solve = \"run --bin solve\"\n"));
+        assert_eq!(sync_aliases(&synced), synced);
+
+        assert!(sync_aliases("").starts_with("[alias]\nscaffold = "));
+    }
+}
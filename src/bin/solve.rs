@@ -0,0 +1,27 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::args::{self, DayArgs};
+use std::process;
+
+fn parse_args(args: &mut pico_args::Arguments) -> Result<(u16, u8, DayArgs), pico_args::Error> {
+    let day_args = DayArgs::parse(args)?;
+    let (year, day) = args::parse_puzzle(args)?;
+    Ok((year, day, day_args))
+}
+
+fn main() {
+    let (year, day, day_args) = args::parse_or_exit(parse_args);
+
+    match advent_of_code::find_day(year, day) {
+        Some(solution) => advent_of_code::run_day(solution, &day_args),
+        None => {
+            eprintln!(
+                "No solution registered for day {} of {}. Try `cargo scaffold {} {}`.",
+                day, year, year, day
+            );
+            process::exit(1);
+        }
+    }
+}
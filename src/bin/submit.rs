@@ -0,0 +1,113 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::Client;
+use advent_of_code::args;
+use advent_of_code::submit::{self, Outcome, Submission, Submissions};
+use advent_of_code::verify::Answers;
+use advent_of_code::Answer;
+use std::process;
+
+struct Args {
+    year: u16,
+    day: u8,
+    part: u8,
+}
+
+/// Takes `[year] <day> <part>`.
+fn parse_args(args: &mut pico_args::Arguments) -> Result<Args, pico_args::Error> {
+    let first: String = args.free_from_str()?;
+    let second: String = args.free_from_str()?;
+    let (puzzle, part) = match args.opt_free_from_str::<String>()? {
+        Some(part) => (vec![first, second], part),
+        None => (vec![first], second),
+    };
+    let mut puzzle = pico_args::Arguments::from_vec(puzzle.into_iter().map(Into::into).collect());
+    let (year, day) = args::parse_puzzle(&mut puzzle)?;
+    let part = args::parse_part(&part)
+        .map_err(|cause| pico_args::Error::Utf8ArgumentParsingFailed { value: part, cause })?;
+    Ok(Args { year, day, part })
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = args::parse_or_exit(parse_args);
+
+    let solution = match advent_of_code::find_day(args.year, args.day) {
+        Some(solution) => solution,
+        None => exit_with_error(&format!(
+            "No solution registered for day {} of {}.",
+            args.day, args.year
+        )),
+    };
+    let input = match advent_of_code::read_file("inputs", args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => exit_with_error(&format!("Failed to read input: {}", e)),
+    };
+
+    let mut answers = Answers::load(args.year, args.day);
+    let (_, solver) = solution.parts()[args.part as usize - 1];
+    let record = advent_of_code::solve_part(
+        args.year, args.day, args.part, solver, &input, &answers, None,
+    );
+    let answer = match (record.answer, record.error) {
+        (_, Some(e)) => exit_with_error(&format!("Failed to parse input: {}", e)),
+        (Some(answer), None) => answer,
+        (None, None) => exit_with_error(&format!("Part {} is not solved yet.", args.part)),
+    };
+    if let Answer::Picture(picture) = &answer {
+        println!("{}", picture);
+    }
+    let answer = match answer.submission() {
+        Some(answer) => answer,
+        None => exit_with_error("Could not read the letters of the answer, enter them by hand."),
+    };
+    println!("Answer for part {}: {}", args.part, answer);
+
+    let mut submissions = Submissions::load(args.year, args.day);
+    if let Err(reason) = submissions.check(args.part, &answer, submit::now()) {
+        exit_with_error(&format!("Not submitting, {}.", reason));
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => exit_with_error(&format!("Failed to submit answer: {}", e)),
+    };
+    let time = submit::now();
+    let outcome = match client.submit(args.year, args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => exit_with_error(&format!("Failed to submit answer: {}", e)),
+    };
+    println!("---");
+    println!("{}", outcome);
+
+    if outcome != Outcome::WrongLevel {
+        submissions.push(Submission {
+            time,
+            part: args.part,
+            outcome,
+            answer: answer.clone(),
+        });
+        if let Err(e) = submissions.save(args.year, args.day) {
+            eprintln!("Failed to record submission: {}", e);
+        }
+    }
+
+    if outcome == Outcome::Correct {
+        answers.insert(args.part, &answer);
+        match answers.save(args.year, args.day) {
+            Ok(_) => println!(
+                "🎄 Stored the answer in \"{}\".",
+                advent_of_code::file_path("answers", args.year, args.day).display()
+            ),
+            Err(e) => eprintln!("Failed to store answer: {}", e),
+        }
+    } else {
+        process::exit(1);
+    }
+}
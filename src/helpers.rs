@@ -2,3 +2,15 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod cycle;
+mod direction;
+mod grid;
+mod interval;
+pub mod parsing;
+mod point;
+pub mod search;
+
+pub use direction::{Dir4, Dir8};
+pub use grid::Grid;
+pub use interval::{Integer, IntervalSet};
+pub use point::{Point2, Point3};
@@ -0,0 +1,82 @@
+//! Cycle detection for simulations that would take too many steps to run to the end.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating, and after how many steps it repeats.
+///
+/// Step `i` is the state after `i` steps, so step 0 is the initial state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    /// The number of steps after which the states repeat.
+    pub len: usize,
+}
+
+impl Cycle {
+    /// Finds the cycle in a sequence of keys, by remembering every key until one repeats.
+    ///
+    /// The key of a step only has to capture what decides the following steps, so e.g. a height
+    /// that keeps growing can be left out of it and tracked next to the keys instead. Returns
+    /// `None` if the keys end before one repeats.
+    pub fn detect<K: Eq + Hash>(keys: impl IntoIterator<Item = K>) -> Option<Cycle> {
+        let mut seen = HashMap::new();
+        for (step, key) in keys.into_iter().enumerate() {
+            if let Some(start) = seen.insert(key, step) {
+                return Some(Cycle {
+                    start,
+                    len: step - start,
+                });
+            }
+        }
+        None
+    }
+
+    /// The earliest step that has the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start + self.len {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// How many whole cycles lie between [`Cycle::equivalent`] of `n` and `n`.
+    ///
+    /// Multiplied with how much e.g. a height grows during one cycle, this gives how much it grows
+    /// in the steps that were skipped.
+    pub fn repeats(&self, n: usize) -> usize {
+        (n - self.equivalent(n)) / self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    fn naive_nth(n: usize) -> u32 {
+        (0..n).fold(3, |x, _| step(&x))
+    }
+
+    #[test]
+    fn test_detect() {
+        let cycle = Cycle::detect(std::iter::successors(Some(3), |x| Some(step(x)))).unwrap();
+        assert_eq!(naive_nth(cycle.start), naive_nth(cycle.start + cycle.len));
+        assert_ne!(
+            naive_nth(cycle.start - 1),
+            naive_nth(cycle.start + cycle.len - 1)
+        );
+        for n in [0, 1, 5, 1000] {
+            assert_eq!(naive_nth(cycle.equivalent(n)), naive_nth(n));
+        }
+
+        let cycle = Cycle { start: 2, len: 3 };
+        assert_eq!(cycle.equivalent(4), 4);
+        assert_eq!((cycle.equivalent(12), cycle.repeats(12)), (3, 3));
+        assert_eq!(Cycle::detect([1, 2, 3]), None);
+    }
+}
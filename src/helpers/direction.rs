@@ -0,0 +1,126 @@
+use super::point::Point2;
+
+/// One of the four directions along the axes, with north pointing up on screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// One of the four directions along the axes or the four diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// The step that moves one unit into this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir4::North => Point2::new(0, -1),
+            Dir4::East => Point2::new(1, 0),
+            Dir4::South => Point2::new(0, 1),
+            Dir4::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Turns by 90 degrees clockwise.
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl Dir8 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// The step that moves one unit into this direction, diagonally for the diagonals.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Dir8::North => Point2::new(0, -1),
+            Dir8::NorthEast => Point2::new(1, -1),
+            Dir8::East => Point2::new(1, 0),
+            Dir8::SouthEast => Point2::new(1, 1),
+            Dir8::South => Point2::new(0, 1),
+            Dir8::SouthWest => Point2::new(-1, 1),
+            Dir8::West => Point2::new(-1, 0),
+            Dir8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Dir8 {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir4::East.opposite(), Dir4::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::SouthWest.opposite(), Dir8::NorthEast);
+        assert_eq!(Dir8::from(Dir4::South), Dir8::South);
+    }
+
+    #[test]
+    fn test_offset_matches_rotation() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().offset(), dir.offset().rotate_right());
+            assert_eq!(Dir8::from(dir).offset(), dir.offset());
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.opposite().offset(), -dir.offset());
+        }
+    }
+}
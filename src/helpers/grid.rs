@@ -0,0 +1,336 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use super::point::Point2;
+use crate::parse::ParseError;
+
+/// A position in a grid, as `(x, y)` with the origin in the top left corner.
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` by `height` cells that are all set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells in row order.
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of width {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line of `input`, converting every character with `f`.
+    ///
+    /// `f` returns what it expected instead if it can't convert a character, e.g. `a digit`.
+    /// The lines all have to be of the same length.
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, &'static str>,
+    {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let len = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = f(c).map_err(|expected| {
+                    ParseError::at(input, &line[i..i + c.len_utf8()], expected)
+                })?;
+                cells.push(cell);
+            }
+            let line_width = cells.len() - len;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row of {} cells like the first", width),
+                    ))
+                }
+                Some(_) => (),
+            }
+        }
+        Ok(Grid::from_vec(width.unwrap_or(0).max(1), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        self.contains((x, y)).then_some(y * self.width + x)
+    }
+
+    /// Iterates over all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell, row by row, that matches `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Iterates over the positions up, right, down and left of `position` that are inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &OFFSETS_4)
+    }
+
+    /// Iterates over the positions around `position`, diagonals included, that are inside the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &OFFSETS_8)
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        (x, y): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(position).then_some(position)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width)
+    }
+
+    /// Iterates over the cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Returns the grid mirrored along its diagonal, so that its rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// Returns the grid surrounded by `margin` rows and columns of `value` on every side.
+    pub fn padded(&self, margin: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width + 2 * margin;
+        let mut grid = Grid::new(width, self.height + 2 * margin, value);
+        for (y, row) in self.rows().enumerate() {
+            grid.row_mut(y + margin)[margin..width - margin].clone_from_slice(row);
+        }
+        grid
+    }
+
+    /// Converts every cell with `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "position {:?} is outside of the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "position {:?} is outside of the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Indexes by point, for solutions that move around with signed coordinates.
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2) -> &T {
+        match (usize::try_from(point.x), usize::try_from(point.y)) {
+            (Ok(x), Ok(y)) => &self[(x, y)],
+            _ => panic!("point {} is outside of the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, point: Point2) -> &mut T {
+        match (usize::try_from(point.x), usize::try_from(point.y)) {
+            (Ok(x), Ok(y)) => &mut self[(x, y)],
+            _ => panic!("point {} is outside of the grid", point),
+        }
+    }
+}
+
+/// Renders one line per row, with the cells of a row written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123\n456\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT, |c| c.to_digit(10).ok_or("a digit")).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.find(|&cell| cell == 5), Some((1, 1)));
+        assert_eq!(grid[Point2::new(0, 1)], 4);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Grid::parse("123\n45\n", Ok).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "a row of 3 cells like the first");
+        let e = Grid::parse("123\n4x6\n", |c| c.to_digit(10).ok_or("a digit")).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            r#"line 2, column 2: expected a digit, found "x""#
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(INPUT, Ok).unwrap();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse(INPUT, Ok).unwrap();
+        assert_eq!(grid.row(1), &['4', '5', '6']);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "52");
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "14\n25\n36");
+        assert_eq!(transposed.transpose(), grid);
+        assert_eq!(
+            grid.padded(1, '.').to_string(),
+            ".....\n.123.\n.456.\n....."
+        );
+    }
+}
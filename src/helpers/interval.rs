@@ -0,0 +1,248 @@
+use std::fmt::Debug;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// An integer type that can bound the intervals of an [`IntervalSet`].
+pub trait Integer: Copy + Ord + Debug + Default + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// The next value, or the value itself at the maximum.
+    fn succ(self) -> Self;
+
+    /// The previous value, or the value itself at the minimum.
+    fn pred(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ONE: $t = 1;
+
+            fn succ(self) -> $t {
+                self.saturating_add(1)
+            }
+
+            fn pred(self) -> $t {
+                self.saturating_sub(1)
+            }
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A set of integers, stored as sorted, disjoint and non-adjacent inclusive intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    /// Iterates over the intervals in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Adds all values of `range`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let lo = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end.succ() < start);
+        let hi = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end.succ());
+        if lo < hi {
+            start = start.min(self.intervals[lo].0);
+            end = end.max(self.intervals[hi - 1].1);
+        }
+        self.intervals.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Removes all values of `range`, splitting the intervals it cuts through.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let lo = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end < start);
+        let hi = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end);
+        if lo == hi {
+            return;
+        }
+        let (first, last) = (self.intervals[lo], self.intervals[hi - 1]);
+        let mut rest = Vec::with_capacity(2);
+        if first.0 < start {
+            rest.push((first.0, start.pred()));
+        }
+        if last.1 > end {
+            rest.push((end.succ(), last.1));
+        }
+        self.intervals.splice(lo..hi, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Returns the values of the set that are inside `range`.
+    pub fn clamp(&self, range: RangeInclusive<T>) -> IntervalSet<T> {
+        self.intersection(&IntervalSet::from_iter([range]))
+    }
+
+    /// The number of values in the set.
+    pub fn total_len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |len, &(start, end)| {
+                len + (end - start) + T::ONE
+            })
+    }
+
+    /// Iterates over the intervals of `range` that are not in the set, in ascending order.
+    pub fn gaps(&self, range: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let (start, end) = range.into_inner();
+        let mut next = Some(start).filter(|&start| start <= end);
+        let lo = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end < start);
+        self.intervals[lo..]
+            .iter()
+            .map(Some)
+            .chain([None])
+            .map_while(move |interval| {
+                let from = next?;
+                let gap = match interval {
+                    Some(&(other_start, other_end)) if other_start <= end => {
+                        next = (other_end < end).then(|| other_end.succ());
+                        (from < other_start).then(|| from..=other_start.pred())
+                    }
+                    _ => {
+                        next = None;
+                        Some(from..=end)
+                    }
+                };
+                Some(gap)
+            })
+            .flatten()
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::from_iter([5..=7, 0..=1, 10..=12]);
+        assert_eq!(ranges(&set), vec![0..=1, 5..=7, 10..=12]);
+        set.insert(2..=3);
+        assert_eq!(ranges(&set), vec![0..=3, 5..=7, 10..=12]);
+        set.insert(6..=9);
+        assert_eq!(ranges(&set), vec![0..=3, 5..=12]);
+        let (start, end) = (3, 2);
+        set.insert(start..=end);
+        assert_eq!(set.total_len(), 12);
+        assert!(set.contains(5) && !set.contains(4) && !set.contains(13));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = IntervalSet::from_iter([0..=10, 20..=30]);
+        set.remove(5..=22);
+        assert_eq!(ranges(&set), vec![0..=4, 23..=30]);
+        set.remove(0..=0);
+        set.remove(30..=40);
+        assert_eq!(ranges(&set), vec![1..=4, 23..=29]);
+        set.remove(-5..=50);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let a = IntervalSet::from_iter([0..=4, 10..=14]);
+        let b = IntervalSet::from_iter([3..=11, 20..=20]);
+        assert_eq!(ranges(&a.union(&b)), vec![0..=14, 20..=20]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![3..=4, 10..=11]);
+        assert_eq!(ranges(&a.clamp(2..=12)), vec![2..=4, 10..=12]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set = IntervalSet::from_iter([0..=4, 10..=14]);
+        assert_eq!(
+            set.gaps(-2..=20).collect::<Vec<_>>(),
+            vec![-2..=-1, 5..=9, 15..=20]
+        );
+        assert_eq!(set.gaps(2..=12).collect::<Vec<_>>(), vec![5..=9]);
+        assert_eq!(set.gaps(1..=3).count(), 0);
+        assert_eq!(
+            IntervalSet::<u8>::new().gaps(0..=255).collect::<Vec<_>>(),
+            vec![0..=255]
+        );
+        let full = IntervalSet::from_iter([0..=u8::MAX]);
+        assert_eq!(full.gaps(0..=255).count(), 0);
+    }
+}
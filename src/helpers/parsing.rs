@@ -0,0 +1,199 @@
+//! Parsers for the shapes that puzzle inputs keep coming in.
+//!
+//! Like [`crate::parse::token`], the functions that can fail take the whole `input` next to the
+//! `text` they parse, which has to be a slice of `input`, so that errors point at the right line.
+use std::str::FromStr;
+
+use super::Grid;
+use crate::parse::{self, ParseError};
+
+/// The rest of the line that `text` starts on.
+fn rest_of_line(text: &str) -> &str {
+    &text[..text.find('\n').unwrap_or(text.len())]
+}
+
+/// The signed integers in `text`, like the regex `-?\d+` finds them.
+pub fn int_tokens(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            if bytes[i] == b'-' {
+                i += 1;
+            }
+            let digits = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i > digits {
+                return Some(&text[start..i]);
+            }
+            i = start + 1;
+        }
+        None
+    })
+}
+
+/// Parses all signed integers in `text`, skipping whatever is between them.
+pub fn ints<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    int_tokens(text)
+        .map(|token| parse::token(input, token, "an integer in range"))
+        .collect()
+}
+
+/// Splits `text` into the blocks of lines between blank lines.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Splits a line like `Starting items: 79, 98` into its key and value, without the surrounding
+/// whitespace.
+pub fn key_value<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| ParseError::at(input, line, "a line like 'key: value'"))
+}
+
+/// Parses a grid of characters with one row per line of `text`.
+pub fn char_grid(text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(text, Ok)
+}
+
+/// Matches `text` against a `pattern` in which every `{}` stands for a field, and returns the
+/// fields.
+///
+/// Everything else in the pattern has to appear in `text` as it is, and a field ends where the
+/// text after it in the pattern first appears, or at the end of the line. Fields don't span lines,
+/// but the pattern can, so a whole block can be matched at once. Newlines after the pattern are
+/// ignored. The fields are slices of `text`, ready for [`crate::parse::token`]:
+///
+/// ```
+/// # use advent_of_code::{helpers::parsing::scanf, parse};
+/// let input = "move 3 from 1 to 2";
+/// let [n, from, to] = scanf(input, input, "move {} from {} to {}")?;
+/// assert_eq!(parse::token::<u32>(input, n, "a number")?, 3);
+/// # Ok::<(), advent_of_code::ParseError>(())
+/// ```
+///
+/// # Panics
+///
+/// If `pattern` doesn't have `N` fields, or has two fields next to each other.
+pub fn scanf<'a, const N: usize>(
+    input: &str,
+    text: &'a str,
+    pattern: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let mut literals = pattern.split("{}");
+    let mut rest = expect(input, text, literals.next().unwrap_or(""))?;
+    let mut fields = [""; N];
+    let mut count = 0;
+    for literal in literals {
+        assert!(count < N, "{pattern:?} has more than {N} fields");
+        let end = if literal.is_empty() {
+            assert!(
+                count == N - 1,
+                "{pattern:?} has two fields next to each other"
+            );
+            rest_of_line(rest).len()
+        } else {
+            // the rest of the literal is matched separately to report exactly where it differs.
+            let head = &literal[..literal.find('\n').map_or(literal.len(), |i| i + 1)];
+            match rest.find(head) {
+                Some(end) if !rest[..end].contains('\n') => end,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        rest_of_line(rest),
+                        format!("a value followed by {head:?}"),
+                    ))
+                }
+            }
+        };
+        fields[count] = &rest[..end];
+        rest = expect(input, &rest[end..], literal)?;
+        count += 1;
+    }
+    assert!(count == N, "{pattern:?} has {count} fields instead of {N}");
+
+    let rest = rest.trim_start_matches('\n');
+    if !rest.is_empty() {
+        return Err(ParseError::at(
+            input,
+            rest_of_line(rest),
+            "the end of the text",
+        ));
+    }
+    Ok(fields)
+}
+
+/// Returns what follows `literal` at the start of `text`.
+fn expect<'a>(input: &str, text: &'a str, literal: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(literal).ok_or_else(|| {
+        let matching = text
+            .char_indices()
+            .zip(literal.chars())
+            .find(|&((_, a), b)| a != b)
+            .map_or(text.len().min(literal.len()), |((i, _), _)| i);
+        ParseError::at(
+            input,
+            rest_of_line(&text[matching..]),
+            format!("{:?}", &literal[matching..]),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let input = "Sensor at x=2, y=-18: a-b--3 4-5\n";
+        assert_eq!(
+            int_tokens(input).collect::<Vec<_>>(),
+            vec!["2", "-18", "-3", "4", "-5"]
+        );
+        assert_eq!(ints::<i32>(input, input), Ok(vec![2, -18, -3, 4, -5]));
+        let e = ints::<u8>(input, input).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 18));
+    }
+
+    #[test]
+    fn test_blocks_and_key_value() {
+        let input = "a: 1\nb:2\n\n\nc\n\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["a: 1\nb:2", "c"]);
+        let lines: Vec<_> = input.lines().collect();
+        assert_eq!(key_value(input, lines[1]), Ok(("b", "2")));
+        assert_eq!(key_value(input, lines[4]).unwrap_err().line, 5);
+    }
+
+    #[test]
+    fn test_scanf() {
+        let input = "Monkey 0:\n  Test: divisible by 23\n";
+        let [id, divisor] = scanf(input, input, "Monkey {}:\n  Test: divisible by {}\n").unwrap();
+        assert_eq!((id, divisor), ("0", "23"));
+        assert_eq!(
+            scanf(input, input, "Monkey {}\n{}"),
+            Ok(["0:", "  Test: divisible by 23"])
+        );
+
+        let e = scanf::<2>(input, input, "Monkey {}:\n  Test: divisible by {}.\n").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(2, 22, "a value followed by \".\\n\"", "\"23\"")
+        );
+        let e = scanf::<1>(input, input, "Monkey {}:\n  Test: dividable by 23\n").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(2, 13, "\"dable by 23\\n\"", "\"sible by 23\"")
+        );
+        let e = scanf::<1>(input, input, "Monkey {}:").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (2, 1, "the end of the text")
+        );
+    }
+}
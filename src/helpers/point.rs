@@ -0,0 +1,257 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::direction::{Dir4, Dir8};
+
+/// A point on a plane, with `y` growing downwards like the rows of a grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// A point in space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// The number of horizontal and vertical steps between two points.
+    pub fn manhattan(self, other: Point2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of king's moves between two points.
+    pub fn chebyshev(self, other: Point2) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The sign of each coordinate, which turns a difference into a single step towards it.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates by 90 degrees counterclockwise around the origin, as seen on screen.
+    pub fn rotate_left(self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// Rotates by 90 degrees clockwise around the origin, as seen on screen.
+    pub fn rotate_right(self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// The points up, right, down and left of this one.
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point2> {
+        Dir4::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// The points around this one, diagonals included, clockwise from the one above.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point2> {
+        Dir8::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(self, other: Point3) -> usize {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbours_6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Point2 {
+        Point2::new(x as isize, y as isize)
+    }
+}
+
+/// Converts to a grid position, if neither coordinate is negative.
+impl TryFrom<Point2> for (usize, usize) {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point2) -> Result<(usize, usize), Self::Error> {
+        Ok((usize::try_from(point.x)?, usize::try_from(point.y)?))
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Mul<isize> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: isize) -> $point {
+                $point { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+macro_rules! impl_dir_ops {
+    ($dir:ident) => {
+        impl Add<$dir> for Point2 {
+            type Output = Point2;
+
+            fn add(self, dir: $dir) -> Point2 {
+                self + dir.offset()
+            }
+        }
+
+        impl AddAssign<$dir> for Point2 {
+            fn add_assign(&mut self, dir: $dir) {
+                *self = *self + dir;
+            }
+        }
+    };
+}
+
+impl_dir_ops!(Dir4);
+impl_dir_ops!(Dir8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut point = Point2::new(1, -2) + Point2::new(3, 4) * 2;
+        assert_eq!(point, Point2::new(7, 6));
+        point -= Point2::new(7, 0);
+        assert_eq!(-point, Point2::new(0, -6));
+        assert_eq!(Point2::new(-3, 0).signum(), Point2::new(-1, 0));
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(3, 2, 1),
+            Point3::new(-2, 0, 2)
+        );
+    }
+
+    #[test]
+    fn test_metrics() {
+        let (a, b) = (Point2::new(-1, 2), Point2::new(3, 0));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.chebyshev(b), 4);
+        let (a, b) = (Point3::new(0, 0, 0), Point3::new(1, -5, 2));
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 5);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let point = Point2::new(2, -1);
+        assert_eq!(point.rotate_left(), Point2::new(-1, -2));
+        assert_eq!(point.rotate_right().rotate_left(), point);
+        assert_eq!(point.rotate_right().rotate_right(), -point);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2::new(0, 0);
+        assert_eq!(
+            point.neighbours_4().collect::<Vec<_>>(),
+            vec![
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert_eq!(point.neighbours_8().count(), 8);
+        assert!(point
+            .neighbours_8()
+            .all(|other| point.chebyshev(other) == 1));
+        assert!(Point3::ORIGIN
+            .neighbours_6()
+            .all(|other| Point3::ORIGIN.manhattan(other) == 1));
+        assert_eq!(<(usize, usize)>::try_from(Point2::new(2, 3)), Ok((2, 3)));
+        assert!(<(usize, usize)>::try_from(Point2::new(-1, 3)).is_err());
+    }
+}
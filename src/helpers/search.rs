@@ -0,0 +1,441 @@
+//! Graph searches over implicit graphs, described by a start and a function that lists the
+//! successors of a node.
+//!
+//! Every search reports how many nodes it expanded, which helps to tell whether a change to a
+//! heuristic or a prune actually cut down the work.
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path through the graph, from a start to the node that ended the search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult<N, C> {
+    /// The path that was found, if any.
+    pub path: Option<Path<N, C>>,
+    /// How many nodes were expanded, i.e. had their successors listed or were found to be a goal.
+    pub visited: usize,
+}
+
+impl<N, C: Copy> SearchResult<N, C> {
+    pub fn cost(&self) -> Option<C> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+/// The nodes that were discovered so far, with the node each one was discovered from.
+struct Discovered<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Discovered<N> {
+    fn new() -> Discovered<N> {
+        Discovered {
+            nodes: Vec::new(),
+            parents: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Adds `node` unless it was discovered before, returning its index.
+    fn insert(&mut self, node: N, parent: usize) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.parents.push(parent);
+                entry.insert(index);
+                Some(index)
+            }
+        }
+    }
+}
+
+/// Follows the parents from the node at `index` back to a start, which has no parent.
+fn reconstruct<N: Clone>(nodes: &[N], parents: &[usize], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].clone()];
+    while parents[index] != usize::MAX {
+        index = parents[index];
+        path.push(nodes[index].clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Finds a path with the fewest steps from `start` to a node that satisfies `is_goal`.
+pub fn bfs<N, FN, IN, FG>(start: N, successors: FN, is_goal: FG) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    bfs_multi([start], successors, is_goal)
+}
+
+/// Like [`bfs`], but starts from all of `starts` at once and finds the path from the closest one.
+pub fn bfs_multi<N, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut is_goal: FG,
+) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut discovered = Discovered::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(index) = discovered.insert(start, usize::MAX) {
+            queue.push_back((index, 0));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some((index, steps)) = queue.pop_front() {
+        visited += 1;
+        let node = discovered.nodes[index].clone();
+        if is_goal(&node) {
+            return SearchResult {
+                path: Some(Path {
+                    nodes: reconstruct(&discovered.nodes, &discovered.parents, index),
+                    cost: steps,
+                }),
+                visited,
+            };
+        }
+        for successor in successors(&node) {
+            if let Some(successor) = discovered.insert(successor, index) {
+                queue.push_back((successor, steps + 1));
+            }
+        }
+    }
+    SearchResult {
+        path: None,
+        visited,
+    }
+}
+
+/// Finds a cheapest path from `start` to a node that satisfies `is_goal`.
+///
+/// `successors` lists the neighbours of a node together with the cost of moving there.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, is_goal: FG) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but expands the nodes that `heuristic` estimates to be closest to a goal
+/// first.
+///
+/// The heuristic must never overestimate the remaining cost, or the path may not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut costs = vec![C::default()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    }]);
+
+    let mut visited = 0;
+    while let Some(Candidate { cost, index, .. }) = heap.pop() {
+        if cost > costs[index] {
+            // a cheaper way to this node was found after this candidate was queued.
+            continue;
+        }
+        visited += 1;
+        let node = nodes[index].clone();
+        if is_goal(&node) {
+            return SearchResult {
+                path: Some(Path {
+                    nodes: reconstruct(&nodes, &parents, index),
+                    cost,
+                }),
+                visited,
+            };
+        }
+        for (successor, step) in successors(&node) {
+            let successor_cost = cost + step;
+            let successor_index = match indices.entry(successor) {
+                Entry::Occupied(entry) => {
+                    let successor_index = *entry.get();
+                    if costs[successor_index] <= successor_cost {
+                        continue;
+                    }
+                    costs[successor_index] = successor_cost;
+                    parents[successor_index] = index;
+                    successor_index
+                }
+                Entry::Vacant(entry) => {
+                    let successor_index = nodes.len();
+                    nodes.push(entry.key().clone());
+                    parents.push(index);
+                    costs.push(successor_cost);
+                    entry.insert(successor_index);
+                    successor_index
+                }
+            };
+            heap.push(Candidate {
+                estimate: successor_cost + heuristic(&nodes[successor_index]),
+                cost: successor_cost,
+                index: successor_index,
+            });
+        }
+    }
+    SearchResult {
+        path: None,
+        visited,
+    }
+}
+
+/// An entry of the open set of [`astar`], ordered so that the max-heap pops the lowest estimate.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // among equal estimates, prefer the candidate that got further already.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+/// Explores everything reachable from `start` depth first to find the node with the highest
+/// `score`. The path of the result leads to that node, and its cost is the score.
+///
+/// Nodes that were explored before are skipped, and so are nodes whose `bound` is not higher
+/// than the best score so far. The bound must be at least the score of every node reachable
+/// from a node, or the best node may be pruned. Successors are explored in the order they
+/// are listed, so listing the promising ones first finds a good score early and prunes more.
+pub fn dfs_max<N, V, FN, IN, FS, FB>(
+    start: N,
+    mut successors: FN,
+    mut score: FS,
+    mut bound: FB,
+) -> SearchResult<N, V>
+where
+    N: Clone + Eq + Hash,
+    V: Copy + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> V,
+    FB: FnMut(&N) -> V,
+{
+    let mut seen = HashSet::new();
+    let mut stack = vec![(start, 0)];
+    let mut path: Vec<N> = Vec::new();
+    let mut best: Option<Path<N, V>> = None;
+
+    let mut visited = 0;
+    while let Some((node, depth)) = stack.pop() {
+        if best.as_ref().is_some_and(|best| bound(&node) <= best.cost) {
+            continue;
+        }
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        visited += 1;
+        path.truncate(depth);
+        path.push(node.clone());
+
+        let node_score = score(&node);
+        if best.as_ref().is_none_or(|best| node_score > best.cost) {
+            best = Some(Path {
+                nodes: path.clone(),
+                cost: node_score,
+            });
+        }
+
+        let start = stack.len();
+        stack.extend(
+            successors(&node)
+                .into_iter()
+                .map(|successor| (successor, depth + 1)),
+        );
+        stack[start..].reverse();
+    }
+    SearchResult {
+        path: best,
+        visited,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Grid;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> (Grid<char>, (usize, usize), (usize, usize)) {
+        let grid = Grid::parse(MAZE, Ok).unwrap();
+        let start = grid.find(|&c| c == 'S').unwrap();
+        let goal = grid.find(|&c| c == 'E').unwrap();
+        (grid, start, goal)
+    }
+
+    fn open_neighbours(grid: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours_4(position)
+            .filter(|&neighbour| grid[neighbour] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, goal) = maze();
+        let result = bfs(
+            start,
+            |&position| open_neighbours(&grid, position),
+            |&position| position == goal,
+        );
+        let path = result.path.unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!((path.nodes[0], path.nodes[15]), (start, goal));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| open_neighbours(&grid, step[0]).contains(&step[1])));
+
+        let result = bfs(
+            start,
+            |&position| open_neighbours(&grid, position),
+            |_| false,
+        );
+        assert_eq!(result.path, None);
+        assert_eq!(result.visited, MAZE.matches(['S', '.', 'E']).count());
+    }
+
+    #[test]
+    fn test_bfs_multi() {
+        let (grid, _, goal) = maze();
+        let starts = [(0, 4), (7, 0)];
+        let result = bfs_multi(
+            starts,
+            |&position| open_neighbours(&grid, position),
+            |&position| position == goal,
+        );
+        let path = result.path.unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes[0], (7, 0));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // the cost of a move is the digit that is moved onto.
+        let grid = Grid::parse(
+            "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n\
+             1319128137\n1359912421\n3125421639\n1293138521\n2311944581\n",
+            |c| c.to_digit(10).ok_or("a digit"),
+        )
+        .unwrap();
+        let goal = (grid.width() - 1, grid.height() - 1);
+        let successors = |&position: &(usize, usize)| {
+            grid.neighbours_4(position)
+                .map(|neighbour| (neighbour, grid[neighbour]))
+                .collect::<Vec<_>>()
+        };
+
+        let dijkstra = dijkstra((0, 0), successors, |&position| position == goal);
+        let astar = astar(
+            (0, 0),
+            successors,
+            |&(x, y)| (goal.0 - x + goal.1 - y) as u32,
+            |&position| position == goal,
+        );
+        assert_eq!(dijkstra.cost(), Some(40));
+        assert_eq!(astar.cost(), Some(40));
+        assert!(astar.visited <= dijkstra.visited);
+
+        let path = astar.path.unwrap().nodes;
+        assert_eq!(path.iter().skip(1).map(|&p| grid[p]).sum::<u32>(), 40);
+    }
+
+    #[test]
+    fn test_dfs_max() {
+        // the largest sum of numbers on a path from the top of the triangle to its bottom.
+        let triangle = [vec![3], vec![7, 4], vec![2, 4, 6], vec![8, 5, 9, 3]];
+        let successors = |&(row, col, sum): &(usize, usize, u32)| match triangle.get(row + 1) {
+            Some(next) => vec![
+                (row + 1, col, sum + next[col]),
+                (row + 1, col + 1, sum + next[col + 1]),
+            ],
+            None => vec![],
+        };
+        let score = |&(_, _, sum): &(usize, usize, u32)| sum;
+
+        let result = dfs_max((0, 0, 3), successors, score, |&(row, _, sum)| {
+            sum + 9 * (triangle.len() - 1 - row) as u32
+        });
+        let path = result.path.unwrap();
+        assert_eq!(path.cost, 23);
+        assert_eq!(
+            path.nodes
+                .iter()
+                .map(|&(row, col, _)| triangle[row][col])
+                .collect::<Vec<_>>(),
+            vec![3, 7, 4, 9]
+        );
+
+        let unpruned = dfs_max((0, 0, 3), successors, score, |_| u32::MAX);
+        assert_eq!(unpruned.cost(), Some(23));
+        assert!(result.visited < unpruned.visited);
+    }
+}
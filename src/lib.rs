@@ -4,9 +4,97 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
 
+pub use answer::Answer;
+use args::{Args, DayArgs};
+use bench::Baseline;
+pub use parse::ParseError;
+use report::{Record, Reporter};
+use verify::{Answers, Verdict};
+
+/// Solves one part of a puzzle.
+pub type Solver = fn(&str) -> Result<Option<Answer>, ParseError>;
+
+/// What a part of a solution returns: `Option<T>`, or `Result<Option<T>, ParseError>` for parts
+/// that parse their input, where `T` converts into an [`Answer`]. `None` means that the part is
+/// not solved yet.
+pub trait PartResult {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError>;
+}
+
+impl<T: Into<Answer>> PartResult for Option<T> {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>> PartResult for Result<Option<T>, ParseError> {
+    fn into_answer(self) -> Result<Option<Answer>, ParseError> {
+        self.map(|answer| answer.map(Into::into))
+    }
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    pub fn parts(&self) -> [(u8, Solver); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $module:ident) => {
+        $crate::Day {
+            year: $year,
+            day: $day,
+            part_one: |input| $crate::PartResult::into_answer($module::part_one(input)),
+            part_two: |input| $crate::PartResult::into_answer($module::part_two(input)),
+        }
+    };
+}
+
+pub mod answer;
+pub mod aoc;
+pub mod args;
+pub mod bench;
 pub mod helpers;
+pub mod parse;
+pub mod puzzle;
+pub mod report;
+pub mod submit;
+pub mod timings;
+pub mod verify;
+mod years;
+
+pub use years::*;
+
+/// Returns the registered solutions of `year`, if there are any.
+pub fn find_year(year: u16) -> Option<&'static [Day]> {
+    YEARS
+        .iter()
+        .find(|(registered, _)| *registered == year)
+        .map(|(_, days)| *days)
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    find_year(year)?.iter().find(|solution| solution.day == day)
+}
+
+/// The most recent year with registered solutions, used when no year is given.
+pub fn latest_year() -> Option<u16> {
+    YEARS.iter().map(|(year, _)| *year).max()
+}
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -14,81 +102,195 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident) => {{
+        advent_of_code::solve_day(&advent_of_code::Day {
+            year: $year,
+            day: $day,
+            part_one: |input| advent_of_code::PartResult::into_answer($part_one(input)),
+            part_two: |input| advent_of_code::PartResult::into_answer($part_two(input)),
+        });
+    }};
+}
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+/// Entry point of the per-day binaries: runs both parts of `solution` on its input.
+/// Exits with a non-zero status if an answer is incorrect or the input can't be parsed.
+pub fn solve_day(solution: &Day) {
+    run_day(solution, &args::parse_args());
+}
+
+/// Runs both parts of `solution` as requested by `day_args`.
+/// Exits with a non-zero status if an answer is incorrect or the input can't be parsed.
+pub fn run_day(solution: &Day, day_args: &DayArgs) {
+    let reporter = Reporter::new(day_args.args.format);
+    let input = match day_args.input.read(solution.year, solution.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+    let answers = if day_args.input.is_puzzle() {
+        Answers::load(solution.year, solution.day)
+    } else {
+        Answers::default()
+    };
+
+    reporter.begin();
+    let records = run_parts(
+        solution.year,
+        solution.day,
+        &solution.parts(),
+        &input,
+        &answers,
+        &day_args.args,
+        &reporter,
+    );
+    if records
+        .iter()
+        .any(|record| record.is_incorrect() || record.is_error())
+    {
+        process::exit(1);
+    }
+}
+
+/// Runs and reports `parts` of `day`, verifying against `answers` and benchmarking if requested.
+pub fn run_parts(
+    year: u16,
+    day: u8,
+    parts: &[(u8, Solver)],
+    input: &str,
+    answers: &Answers,
+    args: &Args,
+    reporter: &Reporter,
+) -> Vec<Record> {
+    let baseline = args.bench.and_then(|_| Baseline::load(year, day));
+
+    let records: Vec<Record> = parts
+        .iter()
+        .map(|&(part, solver)| {
+            let record = solve_part(year, day, part, solver, input, answers, args.bench);
+            reporter.report(&record);
+            if let (Some(stats), Some(baseline)) = (&record.bench, &baseline) {
+                if let Some(baseline) = baseline.get(part) {
+                    reporter.change(stats, baseline);
                 }
             }
+            record
+        })
+        .collect();
+
+    if args.save_baseline {
+        let mut baseline = Baseline::load(year, day).unwrap_or_default();
+        baseline.update(&records);
+        match baseline.save(year, day) {
+            Ok(_) => reporter.note(day, "Saved benchmark baseline."),
+            Err(e) => eprintln!("Failed to save benchmark baseline: {}", e),
         }
+    }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    records
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Runs one part of a solution against `input` and records its answer and elapsed time.
+/// With `bench`, the part is run that many times and the median is recorded.
+/// The answer is checked against the stored `answers`.
+pub fn solve_part<R: PartResult>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> R,
+    input: &str,
+    answers: &Answers,
+    bench: Option<usize>,
+) -> Record {
+    let (result, elapsed, stats) = match bench {
+        Some(runs) => {
+            let (result, stats) = bench::run(func, input, runs);
+            (result, stats.median, Some(stats))
+        }
+        None => {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed(), None)
+        }
+    };
+
+    let (answer, error) = match result.into_answer() {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
+    let mut record = Record {
+        year,
+        day,
+        part,
+        answer,
+        error,
+        elapsed,
+        verdict: Verdict::Unknown,
+        bench: stats,
+    };
+    record.verdict = answers.verdict(&record);
+    record
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Overrides the directory that puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Resolves `relative` against the crate root, independent of the current working directory.
+pub fn project_path(relative: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Resolves the file of `day` of `year` in `folder`, e.g. `inputs/2022/07.txt`.
+/// Inputs are read from `$AOC_INPUT_DIR` instead if it is set.
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    let dir = match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if folder == "inputs" => PathBuf::from(dir),
+        _ => project_path(folder),
+    };
+    dir.join(year.to_string()).join(format!("{:02}.txt", day))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+#[derive(Debug)]
+pub struct ReadError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read \"{}\": {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads the file at `path`, or stdin if `path` is `-`.
+pub fn read_path(path: impl AsRef<Path>) -> Result<String, ReadError> {
+    let path = path.as_ref();
+    let result = if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|source| ReadError {
+        path: path.to_path_buf(),
+        source,
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads the file of `day` in `folder`, see [`file_path`].
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    read_path(file_path(folder, year, day))
 }
 
 #[cfg(test)]
@@ -96,30 +298,32 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_file_path() {
+        assert_eq!(
+            file_path("examples", 2022, 7),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/2022/07.txt")
         );
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+    #[test]
+    fn test_read_file() {
+        assert!(read_file("examples", 2022, 1).unwrap().starts_with("1000"));
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+        let e = read_file("examples", 2022, 99).unwrap_err();
+        assert_eq!(e.path, project_path("examples/2022/99.txt"));
+        assert!(e.to_string().contains("99.txt"));
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    #[test]
+    fn test_find_day() {
+        let solution = find_day(2022, 7).unwrap();
+        assert_eq!((solution.year, solution.day), (2022, 7));
+        assert!(find_day(2022, 26).is_none());
+        assert!(find_day(1999, 7).is_none());
+        for (year, days) in YEARS {
+            assert!(days.iter().all(|solution| solution.year == *year));
+            assert!(days.windows(2).all(|pair| pair[0].day < pair[1].day));
+        }
+        assert_eq!(latest_year(), YEARS.last().map(|(year, _)| *year));
     }
 }
@@ -2,45 +2,150 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::args::{self, Args};
+use advent_of_code::report::Reporter;
+use advent_of_code::timings::Timings;
+use advent_of_code::verify::Answers;
+use std::process;
+use std::time::Duration;
+
+struct RunnerArgs {
+    args: Args,
+    year: u16,
+    days: Vec<u8>,
+    part: Option<u8>,
+    skip_slow: bool,
+    slow_threshold: Duration,
+}
+
+fn parse_runner_args(args: &mut pico_args::Arguments) -> Result<RunnerArgs, pico_args::Error> {
+    Ok(RunnerArgs {
+        args: Args::parse(args)?,
+        year: match args.opt_value_from_fn("--year", args::parse_year)? {
+            Some(year) => year,
+            None => advent_of_code::latest_year().ok_or_else(|| {
+                pico_args::Error::ArgumentParsingFailed {
+                    cause: String::from("no solutions registered yet, pass --year"),
+                }
+            })?,
+        },
+        days: args
+            .opt_value_from_fn("--days", args::parse_days)?
+            .unwrap_or_else(|| (1..=25).collect()),
+        part: args.opt_value_from_fn("--part", args::parse_part)?,
+        skip_slow: args.contains("--skip-slow"),
+        slow_threshold: Duration::from_millis(
+            args.opt_value_from_str("--slow-threshold")?.unwrap_or(1000),
+        ),
+    })
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let runner_args = args::parse_or_exit(parse_runner_args);
+    let reporter = Reporter::new(runner_args.args.format);
+    let year = runner_args.year;
+    let mut timings = Timings::load(year);
+
+    reporter.begin();
+
+    let mut incorrect = Vec::new();
+    let mut unparsed = Vec::new();
+    let total: Duration = runner_args
+        .days
+        .iter()
+        .map(|&day| {
+            reporter.day_banner(day);
+
+            let solution = match advent_of_code::find_day(year, day) {
+                Some(solution) => solution,
+                None => {
+                    reporter.unsolved_day(year, day);
+                    return Duration::ZERO;
                 }
-            );
+            };
+
+            let parts: Vec<_> = solution
+                .parts()
+                .into_iter()
+                .filter(|(part, _)| runner_args.part.is_none_or(|only| only == *part))
+                .collect();
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+            if runner_args.skip_slow {
+                let previous: Duration = parts
+                    .iter()
+                    .filter_map(|(part, _)| timings.get(day, *part))
+                    .sum();
+                if previous > runner_args.slow_threshold {
+                    reporter.note(
+                        day,
+                        &format!("Skipped (took {:.2?} on the previous run).", previous),
+                    );
+                    return Duration::ZERO;
+                }
             }
+
+            let input = match advent_of_code::read_file("inputs", year, day) {
+                Ok(input) => input,
+                Err(e) => {
+                    reporter.note(day, &format!("Failed to read input: {}", e));
+                    return Duration::ZERO;
+                }
+            };
+
+            let answers = Answers::load(year, day);
+            advent_of_code::run_parts(
+                year,
+                day,
+                &parts,
+                &input,
+                &answers,
+                &runner_args.args,
+                &reporter,
+            )
+            .into_iter()
+            .map(|record| {
+                timings.insert(&record);
+                if record.is_incorrect() {
+                    incorrect.push((day, record.part));
+                }
+                if record.is_error() {
+                    unparsed.push((day, record.part));
+                }
+                if record.is_solved() {
+                    record.elapsed
+                } else {
+                    Duration::ZERO
+                }
+            })
+            .sum()
         })
         .sum();
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    reporter.total(total);
+
+    if let Err(e) = timings.save(year) {
+        eprintln!("Failed to save timings: {}", e);
+    }
+
+    let mut failed = false;
+    for (problem, parts) in [
+        ("Incorrect answers", &incorrect),
+        ("Unparsable inputs", &unparsed),
+    ] {
+        if !parts.is_empty() {
+            eprintln!(
+                "{}: {}",
+                problem,
+                parts
+                    .iter()
+                    .map(|(day, part)| format!("day {} part {}", day, part))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            failed = true;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,112 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A problem with a puzzle input, pointing at where in the input it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the problem, starting at 1.
+    pub line: usize,
+    /// The column of the problem in characters, starting at 1.
+    pub column: usize,
+    /// What the parser was looking for, e.g. `a number`.
+    pub expected: String,
+    /// What the parser got instead, e.g. `"abc"`.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Reports that `found` is not what was `expected`, at the position of `found` in `input`.
+    ///
+    /// `found` has to be a slice of `input`, like the ones returned by `lines` or `split`.
+    /// An empty `found` means that the input ended too early.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (found.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len(),
+            "{:?} is not part of the input it is reported for",
+            found
+        );
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: match found {
+                "" => String::from("nothing"),
+                found => format!("{:?}", found),
+            },
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting its position in `input` if it is not `expected`.
+pub fn token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "1 2\n3 ä x\n";
+        let x = input.split_whitespace().nth(4).unwrap();
+        let e = ParseError::at(input, x, "a number");
+        assert_eq!((e.line, e.column), (2, 5));
+        assert_eq!(
+            e.to_string(),
+            r#"line 2, column 5: expected a number, found "x""#
+        );
+
+        let end = &input[input.len()..];
+        assert_eq!(
+            ParseError::at(input, end, "a line").to_string(),
+            "line 3, column 1: expected a line, found nothing"
+        );
+    }
+
+    #[test]
+    fn test_token() {
+        let input = "12 -3 x";
+        let tokens: Vec<_> = input.split(' ').collect();
+        assert_eq!(token::<i32>(input, tokens[1], "a number"), Ok(-3));
+        assert_eq!(
+            token::<u32>(input, tokens[2], "a number"),
+            Err(ParseError::new(1, 7, "a number", "\"x\""))
+        );
+    }
+}
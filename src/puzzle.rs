@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::Regex;
+
+/// The parts of a puzzle description that the scaffolded tests need.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The contents of every `<pre><code>` block, in order of appearance.
+    pub examples: Vec<String>,
+    /// The expected answer of each part for the example, if the part is unlocked.
+    pub answers: [Option<String>; 2],
+}
+
+impl Puzzle {
+    /// Parses the HTML page of a puzzle, either saved from the browser or downloaded.
+    ///
+    /// The expected answer of a part is the last emphasized code in its description,
+    /// which is where the puzzle text states the answer for the example.
+    pub fn parse(html: &str) -> Puzzle {
+        let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+        let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let answers =
+            Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+
+        let mut puzzle = Puzzle::default();
+        for (part, article) in articles.captures_iter(html).take(2).enumerate() {
+            let article = &article[1];
+            puzzle.examples.extend(
+                blocks
+                    .captures_iter(article)
+                    .map(|block| strip_tags(&block[1])),
+            );
+            puzzle.answers[part] = answers.captures_iter(article).last().map(|answer| {
+                let answer = answer.get(1).or_else(|| answer.get(2)).unwrap();
+                strip_tags(answer.as_str())
+            });
+        }
+        puzzle
+    }
+
+    /// Whether the page includes part two, which only appears after solving part one.
+    pub fn is_complete(html: &str) -> bool {
+        html.matches(r#"<article class="day-desc">"#).count() >= 2
+    }
+}
+
+/// Removes markup such as the `<em>` highlights inside code blocks and decodes entities.
+fn strip_tags(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Replaces the `None` and `Ok(None)` placeholders of the scaffolded tests in `module` with the
+/// expected answers. Tests that were edited already are left alone.
+pub fn fill_asserts(module: &str, answers: &[Option<String>; 2]) -> String {
+    let mut module = module.to_owned();
+    for (func, answer) in ["part_one", "part_two"].iter().zip(answers) {
+        if let Some(answer) = answer {
+            let expected = match answer.parse::<i64>() {
+                Ok(_) => format!("Some({})", answer),
+                Err(_) => format!("Some(String::from({:?}))", answer),
+            };
+            for placeholder in ["None", "Ok(None)"] {
+                let wrapped = placeholder.replace("None", &expected);
+                module = module.replace(
+                    &format!("assert_eq!({}(&input), {});", func, placeholder),
+                    &format!("assert_eq!({}(&input), {});", func, wrapped),
+                );
+            }
+        }
+    }
+    module
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2><p>For example:</p>
+<pre><code>    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+</code></pre>
+<p>In this example, the crate <code>C</code> ends up on top.</p>
+<pre><code>[<em>D</em>] &lt;- top</code></pre>
+<p>The Elves just need to know which crate ends up on top of each stack; in this example, the top crates are <code>C</code> in stack 1, <code>M</code> in stack 2, and <code>Z</code> in stack 3, so you should combine these together and give the Elves the message <code><em>CMZ</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>TLNGFGMFN</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>In this example, the CrateMover 9001 has put the crates in a totally different order: <em><code>MCD</code></em>.</p>
+<p>The total is <code><em>12</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse() {
+        let puzzle = Puzzle::parse(PAGE);
+        assert_eq!(puzzle.examples.len(), 2);
+        assert_eq!(
+            puzzle.examples[0],
+            "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n"
+        );
+        assert_eq!(puzzle.examples[1], "[D] <- top");
+        assert_eq!(
+            puzzle.answers,
+            [Some(String::from("CMZ")), Some(String::from("12"))]
+        );
+        assert!(Puzzle::is_complete(PAGE));
+    }
+
+    #[test]
+    fn test_parse_part_one_only() {
+        let page = &PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()];
+        let puzzle = Puzzle::parse(page);
+        assert_eq!(puzzle.answers, [Some(String::from("CMZ")), None]);
+        assert!(!Puzzle::is_complete(page));
+    }
+
+    #[test]
+    fn test_fill_asserts() {
+        let module = "let input = crate::read_file(\"examples\", 2022, 5).unwrap();\n\
+                      assert_eq!(part_one(&input), None);\n\
+                      assert_eq!(part_two(&input), None);\n";
+        let filled = fill_asserts(
+            module,
+            &[Some(String::from("CMZ")), Some(String::from("12"))],
+        );
+        assert!(filled.contains("assert_eq!(part_one(&input), Some(String::from(\"CMZ\")));"));
+        assert!(filled.contains("assert_eq!(part_two(&input), Some(12));"));
+
+        let filled = fill_asserts(module, &[Some(String::from("CMZ")), None]);
+        assert!(filled.contains("assert_eq!(part_two(&input), None);"));
+    }
+
+    #[test]
+    fn test_fill_asserts_of_lines_template() {
+        let module = include_str!("../templates/lines.tpl")
+            .replace("{{YEAR}}", "2022")
+            .replace("{{DAY}}", "5")
+            .replace("{{ANSWER}}", "String");
+        let filled = fill_asserts(
+            &module,
+            &[Some(String::from("CMZ")), Some(String::from("12"))],
+        );
+        assert!(filled.contains("assert_eq!(part_one(&input), Ok(Some(String::from(\"CMZ\"))));"));
+        assert!(filled.contains("assert_eq!(part_two(&input), Ok(Some(12)));"));
+        assert!(!filled.contains("Ok(None));"));
+    }
+}
@@ -0,0 +1,383 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::answer::Answer;
+use crate::bench::Stats;
+use crate::parse::ParseError;
+use crate::verify::Verdict;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format '{}', expected one of 'text', 'json', or 'csv'",
+                s
+            )),
+        }
+    }
+}
+
+/// The outcome of running one part of a day's solution.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Why the part could not run, if its input could not be parsed.
+    pub error: Option<ParseError>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+    pub bench: Option<Stats>,
+}
+
+impl Record {
+    pub fn unsolved(year: u16, day: u8, part: u8) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            error: None,
+            elapsed: Duration::ZERO,
+            verdict: Verdict::Unknown,
+            bench: None,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    pub fn is_incorrect(&self) -> bool {
+        self.verdict == Verdict::Incorrect
+    }
+
+    fn correct(&self) -> Option<bool> {
+        match self.verdict {
+            Verdict::Unknown => None,
+            Verdict::Correct => Some(true),
+            Verdict::Incorrect => Some(false),
+        }
+    }
+
+    /// Renders the record as a single-line JSON object.
+    /// Integer answers are numbers, and pictures come with the letters read from them if possible.
+    pub fn to_json(&self) -> String {
+        let json = JsonRecord {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            answer: self.answer.as_ref().map(|answer| match answer {
+                Answer::Integer(value) => JsonAnswer::Integer(*value),
+                answer => JsonAnswer::Text(answer.to_string()),
+            }),
+            duration_ns: self.elapsed.as_nanos(),
+            solved: self.is_solved(),
+            correct: self.correct(),
+            letters: self.answer.as_ref().and_then(Answer::letters),
+            error: self.error.as_ref().map(|e| e.to_string()),
+            bench: self.bench.as_ref(),
+        };
+        serde_json::to_string(&json).expect("records serialize to JSON")
+    }
+
+    /// Renders the record as a CSV row matching [`CSV_HEADER`].
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(
+                &self
+                    .answer
+                    .as_ref()
+                    .map(|answer| answer.to_string())
+                    .unwrap_or_default()
+            ),
+            self.elapsed.as_nanos(),
+            self.is_solved(),
+            self.correct()
+                .map(|correct| correct.to_string())
+                .unwrap_or_default(),
+            csv_field(
+                &self
+                    .error
+                    .as_ref()
+                    .map(|e| e.to_string())
+                    .unwrap_or_default()
+            )
+        )
+    }
+}
+
+pub const CSV_HEADER: &str = "year,day,part,answer,duration_ns,solved,correct,error";
+
+/// How a [`Record`] looks in JSON.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<JsonAnswer>,
+    duration_ns: u128,
+    solved: bool,
+    correct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    letters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bench: Option<&'a Stats>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonAnswer {
+    Integer(i128),
+    Text(String),
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Prints records in the requested format.
+/// Banners and totals are only printed for humans, i.e. in the text format.
+pub struct Reporter {
+    format: Format,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Reporter {
+        Reporter { format }
+    }
+
+    pub fn begin(&self) {
+        if let Format::Csv = self.format {
+            println!("{}", CSV_HEADER);
+        }
+    }
+
+    pub fn day_banner(&self, day: u8) {
+        if let Format::Text = self.format {
+            println!("----------");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("----------");
+        }
+    }
+
+    /// Reports a problem with a day that prevents it from running.
+    /// Machine-readable formats keep stdout clean and report on stderr instead.
+    pub fn note(&self, day: u8, message: &str) {
+        match self.format {
+            Format::Text => println!("{}", message),
+            _ => eprintln!("Day {:02}: {}", day, message),
+        }
+    }
+
+    /// Reports a day without a registered solution.
+    pub fn unsolved_day(&self, year: u16, day: u8) {
+        match self.format {
+            Format::Text => println!("Not solved."),
+            _ => {
+                self.report(&Record::unsolved(year, day, 1));
+                self.report(&Record::unsolved(year, day, 2));
+            }
+        }
+    }
+
+    pub fn report(&self, record: &Record) {
+        match self.format {
+            Format::Text => {
+                println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, record.part, ANSI_RESET);
+                match (&record.answer, &record.error) {
+                    (_, Some(e)) => println!("failed to parse input: {}", e),
+                    (Some(answer), None) => println!(
+                        "{}{} {}({}){}",
+                        match answer {
+                            Answer::Picture(picture) => {
+                                println!("{}", picture);
+                                answer.letters().unwrap_or_default()
+                            }
+                            answer => answer.to_string(),
+                        },
+                        match record.verdict {
+                            Verdict::Unknown => "",
+                            Verdict::Correct => " ✅",
+                            Verdict::Incorrect => " ❌",
+                        },
+                        ANSI_ITALIC,
+                        match &record.bench {
+                            Some(stats) => format!(
+                                "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}",
+                                stats.min, stats.median, stats.mean, stats.stddev, stats.runs
+                            ),
+                            None => format!("elapsed: {:.2?}", record.elapsed),
+                        },
+                        ANSI_RESET
+                    ),
+                    (None, None) => println!("not solved."),
+                }
+            }
+            Format::Json => println!("{}", record.to_json()),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+    }
+
+    /// Reports how the median of a benchmarked part changed compared to a saved baseline.
+    pub fn change(&self, stats: &Stats, baseline: &Stats) {
+        if let Format::Text = self.format {
+            println!(
+                "{}median {:+.2}% compared to baseline ({:.2?}){}",
+                ANSI_ITALIC,
+                stats.change(baseline),
+                baseline.median,
+                ANSI_RESET
+            );
+        }
+    }
+
+    pub fn total(&self, total: Duration) {
+        if let Format::Text = self.format {
+            println!(
+                "{}Total:{} {}{:.2}ms{}",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                total.as_secs_f64() * 1000_f64,
+                ANSI_RESET
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let record = Record {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some(Answer::Picture(String::from("#.\n\"#\""))),
+            error: None,
+            elapsed: Duration::from_nanos(74),
+            verdict: Verdict::Unknown,
+            bench: None,
+        };
+        assert_eq!(
+            record.to_json(),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n\"#\"","duration_ns":74,"solved":true,"correct":null}"##
+        );
+        let record = Record {
+            bench: Some(Stats::from_samples(&[Duration::from_nanos(74)])),
+            ..record
+        };
+        assert_eq!(
+            record.to_json(),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n\"#\"","duration_ns":74,"solved":true,"correct":null,"bench":{"runs":1,"min":74,"median":74,"mean":74,"stddev":0}}"##
+        );
+        let record = Record {
+            answer: Some(Answer::Picture(String::from(
+                ".##..\n#..#.\n#..#.\n####.\n#..#.\n#..#.",
+            ))),
+            bench: None,
+            ..record
+        };
+        assert!(record.to_json().contains(r#"\n#..#.","duration_ns":74,"#));
+        assert!(record
+            .to_json()
+            .ends_with(r#""correct":null,"letters":"A"}"#));
+        let record = Record {
+            answer: Some(Answer::Integer(-24933642)),
+            ..record
+        };
+        assert!(record.to_json().contains(r#""answer":-24933642,"#));
+        assert_eq!(
+            Record::unsolved(2022, 3, 1).to_json(),
+            r#"{"year":2022,"day":3,"part":1,"answer":null,"duration_ns":0,"solved":false,"correct":null}"#
+        );
+        let record = Record {
+            error: Some(ParseError::new(2, 1, "a number", "\"x\"")),
+            ..Record::unsolved(2022, 3, 1)
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2022,"day":3,"part":1,"answer":null,"duration_ns":0,"solved":false,"correct":null,"error":"line 2, column 1: expected a number, found \"x\""}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let record = Record {
+            year: 2022,
+            day: 7,
+            part: 1,
+            answer: Some(Answer::Integer(95437)),
+            error: None,
+            elapsed: Duration::from_micros(755),
+            verdict: Verdict::Correct,
+            bench: None,
+        };
+        assert_eq!(record.to_csv(), "2022,7,1,95437,755000,true,true,");
+
+        let record = Record {
+            answer: Some(Answer::Text(String::from("a,\"b\"\nc"))),
+            verdict: Verdict::Incorrect,
+            ..record
+        };
+        assert_eq!(
+            record.to_csv(),
+            "2022,7,1,\"a,\"\"b\"\"\nc\",755000,true,false,"
+        );
+        assert_eq!(Record::unsolved(2022, 3, 2).to_csv(), "2022,3,2,,0,false,,");
+
+        let record = Record {
+            error: Some(ParseError::new(2, 1, "a number", "\"x\"")),
+            ..Record::unsolved(2022, 3, 2)
+        };
+        assert_eq!(
+            record.to_csv(),
+            "2022,3,2,,0,false,,\"line 2, column 1: expected a number, found \"\"x\"\"\""
+        );
+    }
+}
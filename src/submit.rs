@@ -0,0 +1,410 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+/// Whether a wrong answer was too high or too low, if the server told us.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// A wrong answer, after which the server makes us wait before the next submission.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Duration,
+    },
+    /// The answer was submitted too soon after the previous one and was not checked.
+    Wait(Duration),
+    /// The part was solved before, or part one isn't solved yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Parses the `<article>` of the page returned by `POST /YYYY/day/D/answer`.
+    pub fn parse(html: &str) -> Option<Outcome> {
+        if html.contains("That's the right answer") {
+            return Some(Outcome::Correct);
+        }
+        if html.contains("You don't seem to be solving the right level") {
+            return Some(Outcome::WrongLevel);
+        }
+        if html.contains("You gave an answer too recently") {
+            let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait = match left.captures(html) {
+                Some(captures) => {
+                    let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    let seconds: u64 = captures[2].parse().unwrap();
+                    Duration::from_secs(minutes * 60 + seconds)
+                }
+                None => Duration::from_secs(60),
+            };
+            return Some(Outcome::Wait(wait));
+        }
+        if html.contains("That's not the right answer") {
+            let hint = if html.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let minutes = Regex::new(r"(?i)please wait (one|\d+) minutes? before trying again")
+                .unwrap()
+                .captures(html)
+                .map_or(1, |captures| captures[1].parse().unwrap_or(1));
+            return Some(Outcome::Incorrect {
+                hint,
+                wait: Duration::from_secs(minutes * 60),
+            });
+        }
+        None
+    }
+
+    /// How long the server blocks further submissions after this outcome.
+    pub fn wait(&self) -> Duration {
+        match self {
+            Outcome::Incorrect { wait, .. } | Outcome::Wait(wait) => *wait,
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect { hint: None, .. } => "incorrect",
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                ..
+            } => "too-high",
+            Outcome::Incorrect {
+                hint: Some(Hint::TooLow),
+                ..
+            } => "too-low",
+            Outcome::Wait(_) => "wait",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_name(name: &str, wait: Duration) -> Option<Outcome> {
+        let incorrect = |hint| Outcome::Incorrect { hint, wait };
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "incorrect" => Some(incorrect(None)),
+            "too-high" => Some(incorrect(Some(Hint::TooHigh))),
+            "too-low" => Some(incorrect(Some(Hint::TooLow))),
+            "wait" => Some(Outcome::Wait(wait)),
+            "wrong-level" => Some(Outcome::WrongLevel),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer! ⭐"),
+            Outcome::Incorrect { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it is too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it is too low")?,
+                    None => {}
+                }
+                write!(f, ". Wait {:?} before trying again.", wait)
+            }
+            Outcome::Wait(wait) => write!(
+                f,
+                "You gave an answer too recently. Wait {:?} before trying again.",
+                wait
+            ),
+            Outcome::WrongLevel => write!(
+                f,
+                "This part is already solved, or its previous part isn't yet."
+            ),
+        }
+    }
+}
+
+/// One submitted answer and the server's response to it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+/// Every answer submitted for a day, stored in `answers/YYYY/NN.submissions`.
+///
+/// Each line holds `time part outcome wait_seconds answer`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    entries: Vec<Submission>,
+}
+
+impl Submissions {
+    fn path(year: u16, day: u8) -> PathBuf {
+        crate::file_path("answers", year, day).with_extension("submissions")
+    }
+
+    /// Parses the submission log, skipping lines that don't match.
+    pub fn parse(s: &str) -> Submissions {
+        let entries = s
+            .lines()
+            .filter_map(|line| {
+                let mut values = line.splitn(5, ' ');
+                let time = values.next()?.parse().ok()?;
+                let part = values.next()?.parse().ok()?;
+                let name = values.next()?;
+                let wait = Duration::from_secs(values.next()?.parse().ok()?);
+                Some(Submission {
+                    time,
+                    part,
+                    outcome: Outcome::from_name(name, wait)?,
+                    answer: values.next()?.to_owned(),
+                })
+            })
+            .collect();
+        Submissions { entries }
+    }
+
+    /// Loads the submissions of `day`. Returns no submissions if there were none.
+    pub fn load(year: u16, day: u8) -> Submissions {
+        match fs::read_to_string(Submissions::path(year, day)) {
+            Ok(s) => Submissions::parse(&s),
+            Err(_) => Submissions::default(),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = Submissions::path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.entries.push(submission);
+    }
+
+    /// Checks whether submitting `answer` for `part` at `now` could possibly be accepted.
+    /// Returns the reason if it can't, so that we don't burn a submission on it.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(until) = self
+            .entries
+            .iter()
+            .map(|s| s.time + s.outcome.wait().as_secs())
+            .max()
+        {
+            if until > now {
+                return Err(format!(
+                    "the server doesn't accept answers for another {:?}",
+                    Duration::from_secs(until - now)
+                ));
+            }
+        }
+
+        let numeric: Option<i64> = answer.parse().ok();
+        for submission in self.entries.iter().filter(|s| s.part == part) {
+            let known: Option<i64> = submission.answer.parse().ok();
+            match submission.outcome {
+                Outcome::Correct if submission.answer == answer => {
+                    return Err(format!("{} was already accepted", answer))
+                }
+                Outcome::Correct => {
+                    return Err(format!(
+                        "part {} was already solved with {}",
+                        part, submission.answer
+                    ))
+                }
+                Outcome::Incorrect { .. } if submission.answer == answer => {
+                    return Err(format!("{} is known to be wrong", answer))
+                }
+                Outcome::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                    ..
+                } if numeric >= known && known.is_some() => {
+                    return Err(format!(
+                        "{} is too high, {} already was",
+                        answer, submission.answer
+                    ))
+                }
+                Outcome::Incorrect {
+                    hint: Some(Hint::TooLow),
+                    ..
+                } if numeric <= known && numeric.is_some() => {
+                    return Err(format!(
+                        "{} is too low, {} already was",
+                        answer, submission.answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for submission in &self.entries {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                submission.time,
+                submission.part,
+                submission.outcome.name(),
+                submission.outcome.wait().as_secs(),
+                submission.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Seconds since the Unix epoch, as recorded in [`Submission::time`].
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{mock, Client};
+
+    fn article(text: &str) -> String {
+        format!("<main>\n<article><p>{}</p></article>\n</main>", text)
+    }
+
+    fn submission(time: u64, part: u8, outcome: Outcome, answer: &str) -> Submission {
+        Submission {
+            time,
+            part,
+            outcome,
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::parse(&article("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.")),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::parse(&article("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2022/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. [<a href=\"/2022/day/7\">Return to Day 7</a>]")),
+            Some(Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Duration::from_secs(60)
+            })
+        );
+        assert_eq!(
+            Outcome::parse(&article("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.")),
+            Some(Outcome::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: Duration::from_secs(300)
+            })
+        );
+        assert_eq!(
+            Outcome::parse(&article("That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.")),
+            Some(Outcome::Incorrect {
+                hint: None,
+                wait: Duration::from_secs(60)
+            })
+        );
+        assert_eq!(
+            Outcome::parse(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 39s left to wait. [<a href=\"/2022/day/7\">Return to Day 7</a>]")),
+            Some(Outcome::Wait(Duration::from_secs(279)))
+        );
+        assert_eq!(
+            Outcome::parse(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 12s left to wait.")),
+            Some(Outcome::Wait(Duration::from_secs(12)))
+        );
+        assert_eq!(
+            Outcome::parse(&article("You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2022/day/7\">Return to Day 7</a>]")),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(Outcome::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut submissions = Submissions::default();
+        submissions.push(submission(
+            100,
+            1,
+            Outcome::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: Duration::from_secs(60),
+            },
+            "42",
+        ));
+        submissions.push(submission(200, 1, Outcome::Correct, "ZKJFBJFZ"));
+
+        assert_eq!(
+            submissions.to_string(),
+            "100 1 too-low 60 42\n200 1 correct 0 ZKJFBJFZ\n"
+        );
+        assert_eq!(Submissions::parse(&submissions.to_string()), submissions);
+    }
+
+    #[test]
+    fn test_check() {
+        let too_high = Outcome::Incorrect {
+            hint: Some(Hint::TooHigh),
+            wait: Duration::from_secs(60),
+        };
+        let too_low = Outcome::Incorrect {
+            hint: Some(Hint::TooLow),
+            wait: Duration::from_secs(60),
+        };
+        let mut submissions = Submissions::default();
+        submissions.push(submission(100, 1, too_high, "500"));
+        submissions.push(submission(200, 1, too_low, "100"));
+
+        assert!(submissions.check(1, "300", 230).is_err());
+        assert!(submissions.check(1, "300", 260).is_ok());
+        assert!(submissions.check(1, "500", 260).is_err());
+        assert!(submissions.check(1, "501", 260).is_err());
+        assert!(submissions.check(1, "100", 260).is_err());
+        assert!(submissions.check(1, "99", 260).is_err());
+        assert!(submissions.check(2, "99", 260).is_ok());
+
+        submissions.push(submission(300, 1, Outcome::Correct, "300"));
+        assert!(submissions.check(1, "300", 400).is_err());
+        assert!(submissions.check(1, "301", 400).is_err());
+        assert!(submissions.check(2, "301", 400).is_ok());
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = mock::serve(&[(200, &article("That's the right answer!"))]);
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(
+            client.submit(2022, 7, 2, "24933642").unwrap(),
+            Outcome::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=24933642"));
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::report::Record;
+
+/// Where `cargo all` remembers how long each part of a year took on its most recent run,
+/// relative to the crate root.
+pub const TIMINGS_DIR: &str = "target/timings";
+
+/// Durations of the most recent run of each part, keyed by day and part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    entries: BTreeMap<(u8, u8), Duration>,
+}
+
+impl Timings {
+    /// Parses lines of `day part nanoseconds`, skipping lines that don't match.
+    pub fn parse(s: &str) -> Timings {
+        let entries = s
+            .lines()
+            .filter_map(|line| {
+                let mut values = line.split_whitespace();
+                let day = values.next()?.parse().ok()?;
+                let part = values.next()?.parse().ok()?;
+                let nanos = values.next()?.parse().ok()?;
+                Some(((day, part), Duration::from_nanos(nanos)))
+            })
+            .collect();
+        Timings { entries }
+    }
+
+    fn path(year: u16) -> PathBuf {
+        crate::project_path(TIMINGS_DIR).join(format!("{}.txt", year))
+    }
+
+    /// Loads the timings of the previous run of `year`. Returns no timings if there was none.
+    pub fn load(year: u16) -> Timings {
+        match fs::read_to_string(Timings::path(year)) {
+            Ok(s) => Timings::parse(&s),
+            Err(_) => Timings::default(),
+        }
+    }
+
+    pub fn save(&self, year: u16) -> io::Result<()> {
+        let path = Timings::path(year);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.entries.get(&(day, part)).copied()
+    }
+
+    /// Remembers the duration of a solved part.
+    pub fn insert(&mut self, record: &Record) {
+        if record.is_solved() {
+            self.entries
+                .insert((record.day, record.part), record.elapsed);
+        }
+    }
+}
+
+impl std::fmt::Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), elapsed) in &self.entries {
+            writeln!(f, "{} {} {}", day, part, elapsed.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::verify::Verdict;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut timings = Timings::default();
+        timings.insert(&Record {
+            year: 2022,
+            day: 19,
+            part: 2,
+            answer: Some(Answer::Integer(3472)),
+            error: None,
+            elapsed: Duration::from_millis(1500),
+            verdict: Verdict::Unknown,
+            bench: None,
+        });
+        timings.insert(&Record::unsolved(2022, 3, 1));
+
+        assert_eq!(timings.to_string(), "19 2 1500000000\n");
+        assert_eq!(Timings::parse(&timings.to_string()), timings);
+        assert_eq!(timings.get(19, 2), Some(Duration::from_millis(1500)));
+        assert_eq!(timings.get(3, 1), None);
+    }
+
+    #[test]
+    fn test_parse_skips_invalid_lines() {
+        let timings = Timings::parse("1 1 100\ngarbage\n1 2\n");
+        assert_eq!(timings.get(1, 1), Some(Duration::from_nanos(100)));
+        assert_eq!(timings.get(1, 2), None);
+    }
+}
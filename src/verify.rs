@@ -0,0 +1,157 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+
+use crate::report::Record;
+
+/// How a recorded answer compares to the accepted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Unknown,
+    Correct,
+    Incorrect,
+}
+
+/// Accepted answers for a day's real input, read from `answers/YYYY/NN.txt`.
+///
+/// The file holds the answer to part one, a blank line, and the answer to part two.
+/// Either answer may be left out, e.g. while part two is still unsolved.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Answers {
+        let mut blocks = s
+            .split("\n\n")
+            .map(|block| Some(block.trim()).filter(|block| !block.is_empty()));
+        let part_one = blocks.next().flatten().map(String::from);
+        let part_two = blocks.next().flatten().map(String::from);
+        Answers {
+            parts: [part_one, part_two],
+        }
+    }
+
+    /// Loads the stored answers for `day`. A missing answers file means that no answers are known.
+    pub fn load(year: u16, day: u8) -> Answers {
+        match crate::read_file("answers", year, day) {
+            Ok(s) => Answers::parse(&s.replace("\r\n", "\n")),
+            Err(_) => Answers::default(),
+        }
+    }
+
+    /// Saves the answers to `answers/YYYY/NN.txt`.
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = crate::file_path("answers", year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn insert(&mut self, part: u8, answer: &str) {
+        if let 1 | 2 = part {
+            self.parts[part as usize - 1] = Some(answer.trim().to_owned());
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 | 2 => self.parts[part as usize - 1].as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn verdict(&self, record: &Record) -> Verdict {
+        match (&record.answer, self.get(record.part)) {
+            (Some(answer), Some(expected)) if answer.matches(expected) => Verdict::Correct,
+            (Some(_), Some(_)) => Verdict::Incorrect,
+            _ => Verdict::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [part_one, part_two] = &self.parts;
+        write!(f, "{}", part_one.as_deref().unwrap_or_default())?;
+        if let Some(part_two) = part_two {
+            write!(f, "\n\n{}", part_two)?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use std::time::Duration;
+
+    fn record(part: u8, answer: Option<Answer>) -> Record {
+        Record {
+            year: 2022,
+            day: 1,
+            part,
+            answer,
+            error: None,
+            elapsed: Duration::ZERO,
+            verdict: Verdict::Unknown,
+            bench: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("24000\n\n45000\n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45000"));
+
+        let answers = Answers::parse("24000\n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), None);
+
+        let answers = Answers::parse("13140\n\n##..\n###.\n");
+        assert_eq!(answers.get(2), Some("##..\n###."));
+
+        assert_eq!(Answers::parse(""), Answers::default());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::default();
+        answers.insert(2, "45000\n");
+        assert_eq!(answers.to_string(), "\n\n45000\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+
+        answers.insert(1, "24000");
+        assert_eq!(answers.to_string(), "24000\n\n45000\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answers = Answers::parse("24000");
+        assert_eq!(
+            answers.verdict(&record(1, Some(Answer::Integer(24000)))),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.verdict(&record(1, Some(Answer::Integer(24001)))),
+            Verdict::Incorrect
+        );
+        assert_eq!(answers.verdict(&record(1, None)), Verdict::Unknown);
+        assert_eq!(
+            answers.verdict(&record(2, Some(Answer::Integer(45000)))),
+            Verdict::Unknown
+        );
+
+        let answers = Answers::parse("\n\nA");
+        let picture = Answer::Picture(String::from(".##.\n#..#\n#..#\n####\n#..#\n#..#"));
+        assert_eq!(answers.verdict(&record(2, Some(picture))), Verdict::Correct);
+    }
+}
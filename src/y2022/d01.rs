@@ -0,0 +1,53 @@
+use crate::helpers::parsing::blocks;
+use crate::parse::{self, ParseError};
+
+/// The total calories carried by each elf.
+fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    blocks(input)
+        .map(|input_per_elf| {
+            input_per_elf
+                .lines()
+                .map(|line| parse::token::<u32>(input, line, "a number of calories"))
+                .sum()
+        })
+        .collect()
+}
+
+fn top_k_total_calories(input: &str, k: usize) -> Result<u32, ParseError> {
+    let mut total_calories = parse(input)?;
+    total_calories.sort();
+    Ok(total_calories.iter().rev().take(k).sum())
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    top_k_total_calories(input, 1).map(Some)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    top_k_total_calories(input, 3).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(24000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(45000)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            part_one("1000\n\n2000\n3k0\n").unwrap_err().to_string(),
+            r#"line 4, column 1: expected a number of calories, found "3k0""#
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use crate::parse::{self, ParseError};
+
 enum HandShape {
     Rock,
     Paper,
@@ -12,16 +14,6 @@ enum Outcome {
     Draw,
 }
 
-#[derive(Debug)]
-enum StrategyParseFailure {
-    NumColumns(usize),
-    FirstColumn(String),
-    SecondColumn(String),
-}
-
-#[derive(Debug)]
-struct StrategyParseError(StrategyParseFailure);
-
 enum FirstColumn {
     A,
     B,
@@ -29,7 +21,7 @@ enum FirstColumn {
 }
 
 impl FromStr for FirstColumn {
-    type Err = StrategyParseError;
+    type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use FirstColumn::{A, B, C};
@@ -38,9 +30,7 @@ impl FromStr for FirstColumn {
             "A" => Ok(A),
             "B" => Ok(B),
             "C" => Ok(C),
-            _ => Err(StrategyParseError(StrategyParseFailure::FirstColumn(
-                s.to_owned(),
-            ))),
+            _ => Err(()),
         }
     }
 }
@@ -52,7 +42,7 @@ enum SecondColumn {
 }
 
 impl FromStr for SecondColumn {
-    type Err = StrategyParseError;
+    type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use SecondColumn::{X, Y, Z};
@@ -61,9 +51,7 @@ impl FromStr for SecondColumn {
             "X" => Ok(X),
             "Y" => Ok(Y),
             "Z" => Ok(Z),
-            _ => Err(StrategyParseError(StrategyParseFailure::SecondColumn(
-                s.to_owned(),
-            ))),
+            _ => Err(()),
         }
     }
 }
@@ -94,25 +82,12 @@ impl Score {
         }
     }
 
-    fn parse_strategy(s: &str) -> Result<(FirstColumn, SecondColumn), StrategyParseError> {
-        let columns: Vec<&str> = s.split_whitespace().collect();
-        if columns.len() != 2 {
-            return Err(StrategyParseError(StrategyParseFailure::NumColumns(
-                columns.len(),
-            )));
-        }
-
-        Ok((columns[0].parse()?, columns[1].parse()?))
-    }
-
-    fn from_encrypted_strategy(s: &str) -> Result<Score, StrategyParseError> {
+    fn from_encrypted_strategy(first: FirstColumn, second: SecondColumn) -> Score {
         use FirstColumn::{A, B, C};
         use HandShape::{Paper, Rock, Scissors};
         use Outcome::{Defeat, Draw, Win};
         use SecondColumn::{X, Y, Z};
 
-        let (first, second) = Score::parse_strategy(s)?;
-
         let opponent = match first {
             A => Rock,
             B => Paper,
@@ -131,17 +106,15 @@ impl Score {
             (Rock, Rock) | (Paper, Paper) | (Scissors, Scissors) => Draw,
         };
 
-        Ok(Score::from_player_and_outcome(&player, &outcome))
+        Score::from_player_and_outcome(&player, &outcome)
     }
 
-    fn from_decrypted_strategy(s: &str) -> Result<Score, StrategyParseError> {
+    fn from_decrypted_strategy(first: FirstColumn, second: SecondColumn) -> Score {
         use FirstColumn::{A, B, C};
         use HandShape::{Paper, Rock, Scissors};
         use Outcome::{Defeat, Draw, Win};
         use SecondColumn::{X, Y, Z};
 
-        let (first, second) = Score::parse_strategy(s)?;
-
         let opponent = match first {
             A => Rock,
             B => Paper,
@@ -160,33 +133,41 @@ impl Score {
             (Win, Paper) | (Defeat, Rock) | (Draw, Scissors) => Scissors,
         };
 
-        Ok(Score::from_player_and_outcome(&player, &outcome))
+        Score::from_player_and_outcome(&player, &outcome)
     }
 }
 
-fn solve<P>(input: &str, parse: P) -> Result<u32, StrategyParseError>
+/// Parses the columns of a line of the strategy guide, which is part of `input`.
+fn parse_strategy(input: &str, line: &str) -> Result<(FirstColumn, SecondColumn), ParseError> {
+    let columns: Vec<&str> = line.split_whitespace().collect();
+    if columns.len() != 2 {
+        return Err(ParseError::at(input, line, "two columns"));
+    }
+
+    Ok((
+        parse::token(input, columns[0], "'A', 'B', or 'C' in the first column")?,
+        parse::token(input, columns[1], "'X', 'Y', or 'Z' in the second column")?,
+    ))
+}
+
+fn solve<S>(input: &str, score: S) -> Result<u32, ParseError>
 where
-    P: Fn(&str) -> Result<Score, StrategyParseError>,
+    S: Fn(FirstColumn, SecondColumn) -> Score,
 {
     let mut total = 0;
     for line in input.lines() {
-        total += parse(line)?.value;
+        let (first, second) = parse_strategy(input, line)?;
+        total += score(first, second).value;
     }
     Ok(total)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(solve(input, Score::from_encrypted_strategy).unwrap())
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, Score::from_encrypted_strategy).map(Some)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(solve(input, Score::from_decrypted_strategy).unwrap())
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, Score::from_decrypted_strategy).map(Some)
 }
 
 #[cfg(test)]
@@ -195,13 +176,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(15)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(12)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("A Y\nB W\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.found, r#""W""#);
+        assert_eq!(
+            part_two("A Y\nC\n").unwrap_err().to_string(),
+            r#"line 2, column 1: expected two columns, found "C""#
+        );
     }
 }
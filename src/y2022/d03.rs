@@ -0,0 +1,93 @@
+use crate::parse::ParseError;
+
+/// The items in a rucksack or compartment, as a set of bits indexed by priority.
+fn items(input: &str, contents: &str) -> Result<u64, ParseError> {
+    contents.char_indices().try_fold(0, |items, (i, item)| {
+        let priority = match item {
+            'a'..='z' => item as u32 - 'a' as u32 + 1,
+            'A'..='Z' => item as u32 - 'A' as u32 + 27,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    &contents[i..i + item.len_utf8()],
+                    "an item from 'a' to 'z' or 'A' to 'Z'",
+                ))
+            }
+        };
+        Ok(items | 1 << priority)
+    })
+}
+
+/// The priority of the only item in `common`, which has been shared by the rucksacks on `line`.
+fn priority(input: &str, line: &str, common: u64) -> Result<u32, ParseError> {
+    if common.count_ones() != 1 {
+        return Err(ParseError::at(input, line, "exactly one shared item"));
+    }
+    Ok(common.trailing_zeros())
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let mut sum = 0;
+    for line in input.lines() {
+        // every item is a single byte once they are all valid, so the line can be split in half.
+        items(input, line)?;
+        if !line.len().is_multiple_of(2) {
+            return Err(ParseError::at(input, line, "an even number of items"));
+        }
+        let (first, second) = line.split_at(line.len() / 2);
+        sum += priority(input, line, items(input, first)? & items(input, second)?)?;
+    }
+    Ok(Some(sum))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut sum = 0;
+    for group in lines.chunks(3) {
+        if group.len() < 3 {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "a group of three rucksacks",
+            ));
+        }
+        let mut common = u64::MAX;
+        for line in group {
+            common &= items(input, line)?;
+        }
+        sum += priority(input, group[2], common)?;
+    }
+    Ok(Some(sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(157)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(70)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("vJrwpWtwJgWrhcsFMMfFFhFp\nab1b\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = part_one("aéb\n").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(1, 2, "an item from 'a' to 'z' or 'A' to 'Z'", "\"é\"")
+        );
+        let e = part_one("abcd\n").unwrap_err();
+        assert_eq!(e.expected, "exactly one shared item");
+        let e = part_two("ab\nbc\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::helpers::parsing::scanf;
+use crate::helpers::IntervalSet;
+use crate::parse::{self, ParseError};
+
+/// The section assignments of each pair of elves.
+fn parse(input: &str) -> Result<Vec<[IntervalSet<u32>; 2]>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let [a, b, c, d] = scanf(input, line, "{}-{},{}-{}")?;
+            let assignment = |start: &str, end: &str| {
+                let start: u32 = parse::token(input, start, "a section")?;
+                let end = parse::token(input, end, "a section")?;
+                if end < start {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "ranges that end after they start",
+                    ));
+                }
+                Ok(IntervalSet::from_iter([start..=end]))
+            };
+            Ok([assignment(a, b)?, assignment(c, d)?])
+        })
+        .collect()
+}
+
+fn count_pairs<F>(input: &str, f: F) -> Result<Option<u32>, ParseError>
+where
+    F: Fn(&IntervalSet<u32>, &IntervalSet<u32>, &IntervalSet<u32>) -> bool,
+{
+    let pairs = parse(input)?;
+    let count = pairs
+        .iter()
+        .filter(|[first, second]| f(first, second, &first.intersection(second)))
+        .count();
+    Ok(Some(count as u32))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    count_pairs(input, |first, second, overlap| {
+        overlap == first || overlap == second
+    })
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    count_pairs(input, |_, _, overlap| !overlap.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(4)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("2-4,6-8\n2-3;4-5\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = part_two("4-2,6-8\n").unwrap_err();
+        assert_eq!(e.expected, "ranges that end after they start");
+    }
+}
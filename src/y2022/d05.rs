@@ -0,0 +1,130 @@
+use crate::helpers::parsing::{blocks, int_tokens, scanf};
+use crate::parse::{self, ParseError};
+
+#[derive(Clone, Copy)]
+enum Crane {
+    /// Moves one crate at a time.
+    CrateMover9000,
+    /// Moves all crates of a step at once.
+    CrateMover9001,
+}
+
+/// Parses the drawing of the stacks, from the bottom up.
+fn parse_stacks(input: &str, drawing: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut rows = drawing.lines().rev();
+    let labels = rows.next().unwrap_or(drawing);
+    let num_stacks = int_tokens(labels).count();
+    if num_stacks == 0 {
+        return Err(ParseError::at(input, labels, "the numbers of the stacks"));
+    }
+
+    let mut stacks = vec![Vec::new(); num_stacks];
+    for (height, row) in rows.enumerate() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            let Some(cell) = row.get(i * 4..(i * 4 + 3).min(row.len())) else {
+                break;
+            };
+            match cell.as_bytes() {
+                [b'[', c, b']'] if c.is_ascii_alphabetic() && stack.len() == height => {
+                    stack.push(*c as char)
+                }
+                [b'[', _, b']'] if stack.len() < height => {
+                    return Err(ParseError::at(input, cell, "a crate on top of another"))
+                }
+                cell if cell.iter().all(|&c| c == b' ') => (),
+                _ => return Err(ParseError::at(input, cell, "a crate like '[A]'")),
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+fn solve(input: &str, crane: Crane) -> Result<Option<String>, ParseError> {
+    let mut blocks = blocks(input);
+    let (Some(drawing), Some(procedure)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a drawing and a procedure separated by a blank line",
+        ));
+    };
+    let mut stacks = parse_stacks(input, drawing)?;
+
+    for step in procedure.lines() {
+        let [count, from, to] = scanf(input, step, "move {} from {} to {}")?;
+        let stack = |token: &str| match parse::token::<usize>(input, token, "a stack")? {
+            i @ 1.. if i <= stacks.len() => Ok(i - 1),
+            _ => Err(ParseError::at(
+                input,
+                token,
+                format!("one of the {} stacks", stacks.len()),
+            )),
+        };
+        let (from, to) = (stack(from)?, stack(to)?);
+        let num_crates = stacks[from].len();
+        let count = match parse::token::<usize>(input, count, "a number of crates")? {
+            n if n <= num_crates => n,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    count,
+                    format!("at most the {} crates on the stack", num_crates),
+                ))
+            }
+        };
+
+        let mut crates = stacks[from].split_off(num_crates - count);
+        if let Crane::CrateMover9000 = crane {
+            crates.reverse();
+        }
+        stacks[to].extend(crates);
+    }
+
+    Ok(Some(
+        stacks.iter().filter_map(|stack| stack.last()).collect(),
+    ))
+}
+
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    solve(input, Crane::CrateMover9000)
+}
+
+pub fn part_two(input: &str) -> Result<Option<String>, ParseError> {
+    solve(input, Crane::CrateMover9001)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 5).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(String::from("CMZ"))));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 5).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(String::from("MCD"))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 5).unwrap();
+        let e = part_one(&input.replace("[C]", "(C)")).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+        let e = part_one(&input.replace("[Z]", "   ")).unwrap_err();
+        assert_eq!(e.expected, "a crate on top of another");
+        let e = part_one(&input.replace("move 3 from 1", "move 4 from 1")).unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(7, 6, "at most the 3 crates on the stack", "\"4\"")
+        );
+        let e = part_two(&input.replace("to 3", "to 4")).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (7, 18, "one of the 3 stacks")
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use crate::parse::{self, ParseError};
+
+const DISK_SPACE: u64 = 70_000_000;
+const SPACE_NEEDED: u64 = 30_000_000;
+
+/// The index of the root directory in [`FileSystem::nodes`].
+const ROOT: usize = 0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Directory,
+    File,
+}
+
+struct Node {
+    name: String,
+    kind: Kind,
+    /// The size of a file, or the total size of everything in a directory.
+    size: u64,
+    parent: Option<usize>,
+    /// The entries of a directory by name.
+    children: BTreeMap<String, usize>,
+}
+
+/// The directories and files seen in a terminal session.
+pub struct FileSystem {
+    /// All entries, each after its parent.
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    /// Replays the commands of a terminal session and records what `ls` listed.
+    ///
+    /// The session starts in the root directory. `cd ..` in the root stays there, like in a
+    /// shell, and listing a directory again doesn't add its entries twice.
+    pub fn from_history(history: &str) -> Result<FileSystem, ParseError> {
+        let mut file_system = FileSystem {
+            nodes: vec![Node {
+                name: String::new(),
+                kind: Kind::Directory,
+                size: 0,
+                parent: None,
+                children: BTreeMap::new(),
+            }],
+        };
+        let mut directory = ROOT;
+
+        let mut lines = history.lines().peekable();
+        while let Some(command_line) = lines.next() {
+            let end = &command_line[command_line.len()..];
+            let mut parts = command_line.split(' ');
+            let prompt = parts.next().unwrap();
+            if prompt != "$" {
+                return Err(ParseError::at(history, prompt, "'$'"));
+            }
+            let command = parts.next().unwrap_or(end);
+            match command {
+                "cd" => {
+                    let target = parts.next().unwrap_or(end);
+                    directory = match target {
+                        "/" => ROOT,
+                        ".." => file_system.nodes[directory].parent.unwrap_or(ROOT),
+                        "" => return Err(ParseError::at(history, target, "a directory")),
+                        name => match file_system.nodes[directory].children.get(name) {
+                            Some(&child) if file_system.nodes[child].kind == Kind::Directory => {
+                                child
+                            }
+                            Some(_) => return Err(ParseError::at(history, target, "a directory")),
+                            None => file_system.add(directory, name, Kind::Directory, 0),
+                        },
+                    };
+                }
+                "ls" => {
+                    while let Some(entry) = lines.next_if(|line| !line.starts_with('$')) {
+                        let Some((first_part, name)) = entry.split_once(' ') else {
+                            return Err(ParseError::at(history, entry, "an entry like 'dir a'"));
+                        };
+                        let (kind, size) = match first_part {
+                            "dir" => (Kind::Directory, 0),
+                            _ => (
+                                Kind::File,
+                                parse::token(history, first_part, "a file size or 'dir'")?,
+                            ),
+                        };
+                        if !file_system.nodes[directory].children.contains_key(name) {
+                            file_system.add(directory, name, kind, size);
+                        }
+                    }
+                }
+                _ => return Err(ParseError::at(history, command, "'cd' or 'ls'")),
+            };
+        }
+
+        // every entry comes after its parent, so going backwards adds up the sizes bottom-up.
+        for index in (1..file_system.nodes.len()).rev() {
+            let node = &file_system.nodes[index];
+            let (size, parent) = (node.size, node.parent.unwrap());
+            file_system.nodes[parent].size += size;
+        }
+
+        Ok(file_system)
+    }
+
+    /// Adds an entry to `directory`, and returns its index.
+    fn add(&mut self, directory: usize, name: &str, kind: Kind, size: u64) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            kind,
+            size,
+            parent: Some(directory),
+            children: BTreeMap::new(),
+        });
+        self.nodes[directory]
+            .children
+            .insert(name.to_string(), index);
+        index
+    }
+
+    /// The absolute path of an entry, e.g. `/a/e`.
+    fn path(&self, index: usize) -> String {
+        let mut names = Vec::new();
+        let mut node = &self.nodes[index];
+        while let Some(parent) = node.parent {
+            names.push(node.name.as_str());
+            node = &self.nodes[parent];
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Visits the entries depth-first, and every directory's entries by name.
+    fn walk(&self, order: Order) -> Walk<'_> {
+        Walk {
+            nodes: &self.nodes,
+            order,
+            stack: vec![(ROOT, 0, false)],
+        }
+    }
+
+    /// The directories with their indices.
+    fn directories(&self) -> impl Iterator<Item = (usize, &Node)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.kind == Kind::Directory)
+    }
+
+    /// The path and size of the smallest directory that frees up enough space for the update
+    /// when deleted, or `None` if there is enough space already.
+    pub fn directory_to_delete(&self) -> Option<(String, u64)> {
+        let unused = DISK_SPACE.saturating_sub(self.nodes[ROOT].size);
+        let to_free = SPACE_NEEDED.checked_sub(unused).filter(|&size| size > 0)?;
+        self.directories()
+            .filter(|(_, directory)| directory.size >= to_free)
+            .min_by_key(|(_, directory)| directory.size)
+            .map(|(index, directory)| (self.path(index), directory.size))
+    }
+
+    /// Lists the total size of every directory like `du`, with subdirectories before their
+    /// parents.
+    pub fn du(&self) -> String {
+        self.walk(Order::Post)
+            .filter(|&(index, _)| self.nodes[index].kind == Kind::Directory)
+            .map(|(index, _)| format!("{}\t{}\n", self.nodes[index].size, self.path(index)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    /// Every directory before its entries.
+    Pre,
+    /// Every directory after its entries.
+    Post,
+}
+
+/// A depth-first walk over the indices of the entries of a file system, with their depth below
+/// the root.
+struct Walk<'a> {
+    nodes: &'a [Node],
+    order: Order,
+    /// The entries to visit, and whether their own entries have been put on the stack already.
+    stack: Vec<(usize, usize, bool)>,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (index, depth, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some((index, depth));
+            }
+            let children = self.nodes[index]
+                .children
+                .values()
+                .rev()
+                .map(|&child| (child, depth + 1, false));
+            match self.order {
+                Order::Pre => {
+                    self.stack.extend(children);
+                    return Some((index, depth));
+                }
+                Order::Post => {
+                    self.stack.push((index, depth, true));
+                    self.stack.extend(children);
+                }
+            }
+        }
+    }
+}
+
+/// Draws the file system like `tree`, in the format of the puzzle.
+impl Display for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, depth) in self.walk(Order::Pre) {
+            let node = &self.nodes[index];
+            let name = match node.parent {
+                None => "/",
+                Some(_) => &node.name,
+            };
+            let indent = 2 * depth;
+            match node.kind {
+                Kind::Directory => writeln!(f, "{:indent$}- {} (dir)", "", name)?,
+                Kind::File => writeln!(f, "{:indent$}- {} (file, size={})", "", name, node.size)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>, ParseError> {
+    Ok(Some(
+        FileSystem::from_history(input)?
+            .directories()
+            .map(|(_, directory)| directory.size)
+            .filter(|size| size < &100000)
+            .sum(),
+    ))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseError> {
+    let file_system = FileSystem::from_history(input)?;
+    Ok(file_system.directory_to_delete().map(|(_, size)| size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(95437)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(24933642)));
+    }
+
+    #[test]
+    fn test_directory_to_delete() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        let file_system = FileSystem::from_history(&input).unwrap();
+        assert_eq!(
+            file_system.directory_to_delete(),
+            Some((String::from("/d"), 24933642))
+        );
+    }
+
+    #[test]
+    fn test_rendering() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        let file_system = FileSystem::from_history(&input).unwrap();
+        assert_eq!(
+            file_system.du(),
+            "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/\n"
+        );
+        let tree = file_system.to_string();
+        assert!(tree.starts_with("- / (dir)\n  - a (dir)\n    - e (dir)\n"));
+        assert!(tree.contains("\n      - i (file, size=584)\n    - f (file, size=29116)\n"));
+        assert!(tree.ends_with("\n    - k (file, size=7214296)\n"));
+    }
+
+    #[test]
+    fn test_enough_space() {
+        assert_eq!(part_two("$ ls\n40000000 a\n"), Ok(None));
+    }
+
+    #[test]
+    fn test_revisiting() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        let input = input.replacen("$ cd ..\n$ cd ..\n", "$ cd /\n$ cd ..\n$ ls\ndir a\n", 1)
+            + "$ cd /\n$ cd a\n$ ls\n29116 f\n";
+        assert_eq!(part_one(&input), Ok(Some(95437)));
+        assert_eq!(part_two(&input), Ok(Some(24933642)));
+    }
+
+    #[test]
+    fn test_long_history() {
+        let (depth, width) = (1_000, 200_000);
+        let mut input = String::from("$ cd /\n");
+        for _ in 0..depth {
+            input.push_str("$ ls\n1 f\ndir d\n$ cd d\n");
+        }
+        input.push_str("$ cd /\n$ ls\n");
+        input.extend((0..width).map(|i| format!("{} f{}\n", i, i)));
+        let file_system = FileSystem::from_history(&input).unwrap();
+        assert_eq!(file_system.nodes.len(), 2 * depth + width + 1);
+        assert_eq!(
+            file_system.nodes[ROOT].size,
+            depth as u64 + width as u64 * (width as u64 - 1) / 2
+        );
+        assert_eq!(file_system.du().lines().count(), depth + 1);
+        assert_eq!(
+            file_system.walk(Order::Post).count(),
+            file_system.nodes.len()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("$ cd /\n$ ls\n12x a.txt\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (3, 1, r#""12x""#));
+        let e = part_one("$ cd /\n$ rm a.txt\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (2, 3, "'cd' or 'ls'")
+        );
+        let e = part_two("$ ls\n584 i\n$ cd i\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (3, 6, "a directory")
+        );
+    }
+}
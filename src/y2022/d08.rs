@@ -0,0 +1,117 @@
+use crate::helpers::Grid;
+use crate::parse::ParseError;
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    let grid = Grid::parse(input, |c| c.to_digit(10).ok_or("a tree height"))?;
+    if grid.height() == 0 {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a row of trees",
+        ));
+    }
+    Ok(grid)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let grid = parse(input)?;
+
+    fn check<'a>(height: u32, mut other_heights: impl Iterator<Item = &'a u32>) -> bool {
+        other_heights.all(|&other_height| other_height < height)
+    }
+
+    // every tree on the edge is visible.
+    let mut num_visible_trees = grid
+        .iter()
+        .filter(|&((x, y), _)| x == 0 || y == 0 || x == grid.width() - 1 || y == grid.height() - 1)
+        .count();
+    for y in 1..grid.height() - 1 {
+        for x in 1..grid.width() - 1 {
+            let height = grid[(x, y)];
+            let row = grid.row(y);
+            if
+            // left
+            check(height, row[..x].iter())
+                // right
+                || check(height, row[x + 1..].iter())
+                // top
+                || check(height, grid.column(x).take(y))
+                // bottom
+                || check(height, grid.column(x).skip(y + 1))
+            {
+                num_visible_trees += 1;
+            }
+        }
+    }
+    Ok(Some(num_visible_trees as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let grid = parse(input)?;
+
+    fn num_trees<'a>(height: u32, other_heights: impl Iterator<Item = &'a u32>) -> u32 {
+        let mut num = 0;
+        for &other_height in other_heights {
+            num += 1;
+            if other_height >= height {
+                break;
+            }
+        }
+        num
+    }
+
+    let mut scenic_scores = vec![];
+    for y in 1..grid.height() - 1 {
+        for x in 1..grid.width() - 1 {
+            let height = grid[(x, y)];
+            let row = grid.row(y);
+
+            scenic_scores.push(
+                // left
+                num_trees(height, row[..x].iter().rev())
+                // right
+                * num_trees(height, row[x + 1..].iter())
+                // top
+                * num_trees(height, grid.column(x).take(y).rev())
+                // bottom
+                * num_trees(height, grid.column(x).skip(y + 1)),
+            );
+        }
+    }
+
+    Ok(scenic_scores.into_iter().max())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(21)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(8)));
+    }
+
+    #[test]
+    fn test_small_grids() {
+        assert_eq!(part_one("5\n"), Ok(Some(1)));
+        assert_eq!(part_one("12\n"), Ok(Some(2)));
+        assert_eq!(part_one("1\n2\n3\n"), Ok(Some(3)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            part_one("").unwrap_err(),
+            ParseError::new(1, 1, "a row of trees", "nothing")
+        );
+        let e = part_two("30373\n255x2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::helpers::{Dir4, Point2};
+use crate::parse::{self, ParseError};
+
+struct Rope {
+    knots: Vec<Point2>,
+}
+
+impl Rope {
+    fn new(num_knots: usize) -> Rope {
+        debug_assert!(num_knots >= 2);
+        Rope {
+            knots: vec![Point2::ORIGIN; num_knots],
+        }
+    }
+
+    fn tail(&self) -> Point2 {
+        *self.knots.last().unwrap()
+    }
+
+    fn move_head(&mut self, movement: Dir4) {
+        self.knots[0] += movement;
+        for idx in 0..self.knots.len() - 1 {
+            let predecessor = self.knots[idx];
+            let successor = &mut self.knots[idx + 1];
+
+            if predecessor.chebyshev(*successor) != 2 {
+                break;
+            }
+
+            *successor += (predecessor - *successor).signum();
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(Dir4, usize)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((direction, steps)) = line.split_once(' ') else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "a direction and a number of steps",
+                ));
+            };
+            let direction = match direction {
+                "R" => Dir4::East,
+                "L" => Dir4::West,
+                "U" => Dir4::North,
+                "D" => Dir4::South,
+                _ => return Err(ParseError::at(input, direction, "'R', 'L', 'U' or 'D'")),
+            };
+            Ok((direction, parse::token(input, steps, "a number of steps")?))
+        })
+        .collect()
+}
+
+fn solve(input: &str, num_knots: usize) -> Result<u32, ParseError> {
+    let mut rope = Rope::new(num_knots);
+    let mut visited_by_tail: HashSet<Point2> = HashSet::new();
+    visited_by_tail.insert(rope.tail());
+
+    for (direction, steps) in parse(input)? {
+        for _ in 0..steps {
+            rope.move_head(direction);
+            visited_by_tail.insert(rope.tail());
+        }
+    }
+    Ok(visited_by_tail.len() as u32)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, 2).map(Some)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, 10).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(1)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("R 4\nX 2\n").unwrap_err();
+        assert_eq!(e, ParseError::new(2, 1, "'R', 'L', 'U' or 'D'", "\"X\""));
+        let e = part_two("R 4\nU\n").unwrap_err();
+        assert_eq!(e.expected, "a direction and a number of steps");
+    }
+}
@@ -1,16 +1,25 @@
 use std::collections::VecDeque;
 
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
 enum Instruction {
     NoOp,
     AddX(isize),
 }
 
 impl Instruction {
-    fn from_str(input: &str) -> Instruction {
+    /// Parses `line` of the program `input`.
+    fn parse(input: &str, line: &str) -> Result<Instruction, ParseError> {
         use Instruction::*;
-        match input.trim() {
-            "noop" => NoOp,
-            addx => AddX(addx.split(' ').rev().next().unwrap().parse().unwrap()),
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("noop") => Ok(NoOp),
+            Some("addx") => {
+                let value = words.next().unwrap_or(&line[line.len()..]);
+                Ok(AddX(parse::token(input, value, "a value to add")?))
+            }
+            _ => Err(ParseError::at(input, line, "'noop' or 'addx'")),
         }
     }
 }
@@ -20,10 +29,13 @@ struct Instructions {
 }
 
 impl Instructions {
-    fn from_str(input: &str) -> Instructions {
-        Instructions {
-            stack: input.lines().map(Instruction::from_str).collect(),
-        }
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        Ok(Instructions {
+            stack: input
+                .lines()
+                .map(|line| Instruction::parse(input, line))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -35,13 +47,13 @@ impl Iterator for Instructions {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
     let mut cycle: usize = 0;
     let mut x: isize = 1;
     let mut last_report = 0;
     let mut report;
     let mut signal_strengths = 0;
-    for instruction in Instructions::from_str(input) {
+    for instruction in Instructions::parse(input)? {
         use Instruction::*;
         let (duration, x_diff) = match instruction {
             NoOp => (1, 0),
@@ -58,11 +70,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 
         x += x_diff;
     }
-    Some(signal_strengths as u32)
+    Ok(Some(signal_strengths as u32))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut instructions = Instructions::from_str(input);
+pub fn part_two(input: &str) -> Result<Option<Answer>, ParseError> {
+    let mut instructions = Instructions::parse(input)?;
 
     let mut x: isize = 1;
     let mut position: usize = 0;
@@ -90,19 +102,13 @@ pub fn part_two(input: &str) -> Option<String> {
         position += 1;
     }
 
-    Some(
+    Ok(Some(Answer::Picture(
         display
             .chunks(40)
             .map(|row| row.iter().collect::<String>())
             .collect::<Vec<_>>()
             .join("\n"),
-    )
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    )))
 }
 
 #[cfg(test)]
@@ -111,13 +117,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        let input = crate::read_file("examples", 2022, 10).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(13140)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file("examples", 2022, 10).unwrap();
         let expected = "
         ##..##..##..##..##..##..##..##..##..##..\n\
         ###...###...###...###...###...###...###.\n\
@@ -128,6 +134,15 @@ mod tests {
         "
         .trim()
         .to_owned();
-        assert_eq!(part_two(&input), Some(expected));
+        assert_eq!(part_two(&input), Ok(Some(Answer::Picture(expected))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("noop\naddx 3\nmulx 2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.expected, "'noop' or 'addx'");
+        let e = part_one("addx\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 5, "nothing"));
     }
 }
@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+
+use crate::helpers::parsing::{blocks, scanf};
+use crate::parse::{self, ParseError};
+
+#[derive(Debug)]
+enum Operation {
+    Add(usize),
+    Multiply(usize),
+    Square,
+}
+
+impl Operation {
+    /// Parses the right-hand side of an operation, e.g. `old * 19`, which is part of `input`.
+    fn parse(input: &str, expression: &str) -> Result<Operation, ParseError> {
+        use Operation::*;
+
+        let words: Vec<&str> = expression.split_whitespace().collect();
+        if words.len() != 3 {
+            return Err(ParseError::at(
+                input,
+                expression,
+                "an operation like 'old * 19'",
+            ));
+        }
+        if words[0] != "old" {
+            return Err(ParseError::at(input, words[0], "'old'"));
+        }
+        match (words[1], words[2]) {
+            ("*", "old") => Ok(Square),
+            ("+", "old") => Ok(Multiply(2)),
+            ("*", operand) => Ok(Multiply(parse::token(input, operand, "a number or 'old'")?)),
+            ("+", operand) => Ok(Add(parse::token(input, operand, "a number or 'old'")?)),
+            (operator, _) => Err(ParseError::at(input, operator, "'*' or '+'")),
+        }
+    }
+
+    fn perform(&self, input: usize) -> usize {
+        use Operation::*;
+        match self {
+            Add(summand) => input + summand,
+            Multiply(multiplicand) => input * multiplicand,
+            Square => input * input,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisible_by: usize,
+    idx_true: usize,
+    idx_false: usize,
+}
+
+impl Test {
+    fn perform(&self, input: usize) -> usize {
+        if input.is_multiple_of(self.divisible_by) {
+            self.idx_true
+        } else {
+            self.idx_false
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Item {
+    worry_level: usize,
+}
+
+impl Item {
+    fn inspect(&mut self, operation: &Operation, relief: &bool, max_worry_level: &usize) {
+        let mut new_worry_level = operation.perform(self.worry_level);
+
+        if *relief {
+            new_worry_level /= 3;
+        }
+        new_worry_level %= max_worry_level;
+
+        self.worry_level = new_worry_level;
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: VecDeque<Item>,
+    operation: Operation,
+    test: Test,
+    num_inspections: usize,
+}
+
+impl Monkey {
+    fn new(items: VecDeque<Item>, operation: Operation, test: Test) -> Monkey {
+        Monkey {
+            items,
+            operation,
+            test,
+            num_inspections: 0,
+        }
+    }
+    fn inspect_and_throw(&mut self, relief: &bool, max_worry_level: &usize) -> (usize, Item) {
+        let mut item = self.items.pop_front().unwrap();
+
+        item.inspect(&self.operation, relief, max_worry_level);
+        self.num_inspections += 1;
+
+        let idx = self.test.perform(item.worry_level);
+
+        (idx, item)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    let mut targets = Vec::new();
+    for block in blocks(input) {
+        let [_, items, operation, divisible_by, idx_true, idx_false] = scanf(
+            input,
+            block,
+            "Monkey {}:
+  Starting items: {}
+  Operation: new = {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}",
+        )?;
+
+        let items = items
+            .split(',')
+            .map(|worry_level| {
+                Ok(Item {
+                    worry_level: parse::token(input, worry_level.trim(), "a worry level")?,
+                })
+            })
+            .collect::<Result<VecDeque<Item>, ParseError>>()?;
+        let operation = Operation::parse(input, operation)?;
+        let test = Test {
+            divisible_by: parse::token(input, divisible_by, "a divisor")?,
+            idx_true: parse::token(input, idx_true, "a monkey")?,
+            idx_false: parse::token(input, idx_false, "a monkey")?,
+        };
+
+        monkeys.push(Monkey::new(items, operation, test));
+        targets.extend([idx_true, idx_false]);
+    }
+
+    for target in targets {
+        if target.parse::<usize>().unwrap() >= monkeys.len() {
+            return Err(ParseError::at(
+                input,
+                target,
+                format!("one of the {} monkeys", monkeys.len()),
+            ));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn solve(input: &str, rounds: usize, relief: bool) -> Result<u64, ParseError> {
+    let mut monkeys = parse(input)?;
+    let max_worry_level = monkeys
+        .iter()
+        .map(|monkey| monkey.test.divisible_by)
+        .product();
+
+    for _ in 0..rounds {
+        for idx in 0..monkeys.len() {
+            let indexed_items = {
+                let monkey = &mut monkeys[idx];
+                let mut indexed_items = Vec::new();
+                while !monkey.items.is_empty() {
+                    indexed_items.push(monkey.inspect_and_throw(&relief, &max_worry_level))
+                }
+                indexed_items
+            };
+            for (idx, item) in indexed_items {
+                monkeys[idx].items.push_back(item);
+            }
+        }
+    }
+
+    let mut num_inspections = monkeys
+        .iter()
+        .map(|monkey| monkey.num_inspections as u64)
+        .collect::<Vec<_>>();
+    num_inspections.sort();
+    Ok(num_inspections.iter().rev().take(2).product::<u64>())
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>, ParseError> {
+    solve(input, 20, true).map(Some)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseError> {
+    solve(input, 10_000, false).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(10605)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(2713310158)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        let e = part_one(&input.replacen("old * 19", "old - 19", 1)).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 24));
+        assert_eq!(e.expected, "'*' or '+'");
+        let e = part_one(&input.replacen("monkey 3", "monkey 7", 1)).unwrap_err();
+        assert_eq!((e.line, e.column), (6, 31));
+        assert_eq!(e.expected, "one of the 4 monkeys");
+    }
+}
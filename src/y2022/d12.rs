@@ -0,0 +1,62 @@
+use crate::helpers::search::{bfs_multi, SearchResult};
+use crate::helpers::Grid;
+use crate::parse::ParseError;
+
+type Position = (usize, usize);
+
+/// The height map, with the start and the goal marked as elevation 0 and 27.
+fn parse(input: &str, inline_start: bool) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(input, |c| match c {
+        'S' => Ok(usize::from(inline_start)),
+        'E' => Ok(27),
+        'a'..='z' => Ok(c as usize - 'a' as usize + 1),
+        _ => Err("a lowercase letter, 'S' or 'E'"),
+    })
+}
+
+fn successors(grid: &Grid<usize>, position: Position) -> Vec<Position> {
+    let max_successor_elevation = grid[position] + 1;
+    grid.neighbours_4(position)
+        .filter(|&neighbour| grid[neighbour] <= max_successor_elevation)
+        .collect()
+}
+
+/// Finds the fewest steps to the goal from the closest of the squares at `start_elevation`.
+fn solve(grid: &Grid<usize>, start_elevation: usize) -> SearchResult<Position, usize> {
+    let starts = grid
+        .iter()
+        .filter(|(_, &elevation)| elevation == start_elevation)
+        .map(|(position, _)| position);
+    bfs_multi(
+        starts,
+        |&position| successors(grid, position),
+        |&position| grid[position] == 27,
+    )
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let grid = parse(input, false)?;
+    Ok(solve(&grid, 0).cost().map(|num_steps| num_steps as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let grid = parse(input, true)?;
+    Ok(solve(&grid, 1).cost().map(|num_steps| num_steps as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(31)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(29)));
+    }
+}
@@ -0,0 +1,202 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::helpers::parsing::{blocks, scanf};
+use crate::parse::{self, ParseError};
+
+#[derive(PartialEq, Eq)]
+enum Node {
+    Internal { children: Vec<Node> },
+    Leaf { value: usize },
+}
+
+impl Node {
+    /// Parses a whole line of `input` as a packet.
+    fn parse(input: &str, line: &str) -> Result<Node, ParseError> {
+        match Node::parse_prefix(input, line)? {
+            (node, "") => Ok(node),
+            (_, rest) => Err(ParseError::at(
+                input,
+                first_char(rest),
+                "the end of the packet",
+            )),
+        }
+    }
+
+    /// Parses the node at the start of `packet`, returning it and the rest of `packet`.
+    fn parse_prefix<'a>(input: &str, packet: &'a str) -> Result<(Node, &'a str), ParseError> {
+        use Node::*;
+
+        let Some(mut rest) = packet.strip_prefix('[') else {
+            let digits = packet
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(packet.len());
+            if digits == 0 {
+                return Err(ParseError::at(input, first_char(packet), "a number or '['"));
+            }
+            let value = parse::token(input, &packet[..digits], "a number")?;
+            return Ok((Leaf { value }, &packet[digits..]));
+        };
+
+        let mut children = Vec::new();
+        if let Some(rest) = rest.strip_prefix(']') {
+            return Ok((Internal { children }, rest));
+        }
+        loop {
+            let (child, after) = Node::parse_prefix(input, rest)?;
+            children.push(child);
+            if let Some(after) = after.strip_prefix(',') {
+                rest = after;
+            } else if let Some(after) = after.strip_prefix(']') {
+                return Ok((Internal { children }, after));
+            } else {
+                return Err(ParseError::at(input, first_char(after), "',' or ']'"));
+            }
+        }
+    }
+}
+
+/// The first character of `s`, as a slice of it.
+fn first_char(s: &str) -> &str {
+    &s[..s.chars().next().map_or(0, char::len_utf8)]
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Node::*;
+        match (self, other) {
+            (Leaf { value: self_value }, Leaf { value: other_value }) => {
+                self_value.cmp(other_value)
+            }
+            (Leaf { value }, Internal { .. }) => {
+                let new_self = Internal {
+                    children: vec![Leaf { value: *value }],
+                };
+                new_self.cmp(other)
+            }
+            (Internal { .. }, Leaf { value }) => {
+                let new_other = Internal {
+                    children: vec![Leaf { value: *value }],
+                };
+                self.cmp(&new_other)
+            }
+            (
+                Internal {
+                    children: self_children,
+                },
+                Internal {
+                    children: other_children,
+                },
+            ) => {
+                for (self_child, other_child) in self_children.iter().zip(other_children.iter()) {
+                    match self_child.cmp(other_child) {
+                        Ordering::Equal => (),
+                        ordering => return ordering,
+                    }
+                }
+                self_children.len().cmp(&other_children.len())
+            }
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Node::*;
+        match self {
+            Leaf { value } => write!(f, "{value}"),
+            Internal { children } => {
+                write!(f, "[")?;
+                for (idx, child) in children.iter().enumerate() {
+                    child.fmt(f)?;
+                    if idx < children.len() - 1 {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let mut sum = 0;
+    for (idx, pair) in blocks(input).enumerate() {
+        let [left, right] = scanf(input, pair, "{}\n{}")?;
+        let left = Node::parse(input, left)?;
+        let right = Node::parse(input, right)?;
+
+        if let Ordering::Less = left.cmp(&right) {
+            sum += idx + 1;
+        }
+    }
+    Ok(Some(sum as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let packets: Vec<Node> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| Node::parse(input, line))
+        .collect::<Result<_, _>>()?;
+    let divider_packets: Vec<Node> = [2, 6]
+        .into_iter()
+        .map(|value| Node::Internal {
+            children: vec![Node::Internal {
+                children: vec![Node::Leaf { value }],
+            }],
+        })
+        .collect();
+
+    let mut all_packets: Vec<&Node> = packets.iter().chain(divider_packets.iter()).collect();
+    all_packets.sort();
+
+    Ok(Some(
+        all_packets
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, packet)| {
+                if divider_packets.contains(packet) {
+                    Some(idx + 1)
+                } else {
+                    None
+                }
+            })
+            .product::<usize>() as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(140)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("[1,[2]]\n[1,2 3]\n").unwrap_err();
+        assert_eq!(e, ParseError::new(2, 5, "',' or ']'", "\" \""));
+        let e = part_one("[1,[2]]\n[1,2]]\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+        let e = part_one("[[]]\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.expected, "a value followed by \"\\n\"");
+        let e = part_two("[x]\n").unwrap_err();
+        assert_eq!(e.expected, "a number or '['");
+    }
+}
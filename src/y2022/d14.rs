@@ -0,0 +1,230 @@
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+use crate::helpers::parsing::scanf;
+use crate::helpers::Grid;
+use crate::parse::{self, ParseError};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Element {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Element::*;
+        f.write_str(match self {
+            Air => ".",
+            Rock => "#",
+            Sand => "o",
+        })
+    }
+}
+
+enum Axis {
+    X(usize),
+    Y(usize),
+}
+struct CoordinateRangeIterator {
+    axis: Axis,
+    range: RangeInclusive<usize>,
+}
+
+impl CoordinateRangeIterator {
+    fn new(start: (usize, usize), end: (usize, usize)) -> Option<Self> {
+        let axis;
+        let range;
+        if start.0 == end.0 {
+            axis = Axis::X(start.0);
+            range = start.1.min(end.1)..=start.1.max(end.1);
+        } else if start.1 == end.1 {
+            axis = Axis::Y(start.1);
+            range = start.0.min(end.0)..=start.0.max(end.0);
+        } else {
+            return None;
+        }
+        Some(Self { axis, range })
+    }
+}
+
+impl Iterator for CoordinateRangeIterator {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.range.next(), &self.axis) {
+            (Some(y), Axis::X(x)) => Some((*x, y)),
+            (Some(x), Axis::Y(y)) => Some((x, *y)),
+            _ => None,
+        }
+    }
+}
+
+/// Where the sand comes from.
+const SOURCE: (usize, usize) = (500, 0);
+
+struct Cave {
+    grid: Grid<Element>,
+    /// How far the grid extends to the left of `x = 0`, so that sand never reaches its sides.
+    offset: usize,
+}
+
+impl Cave {
+    fn parse(input: &str, has_floor: bool) -> Result<Cave, ParseError> {
+        let mut height = 0;
+        let mut max_x = SOURCE.0;
+        let mut vertices: Vec<Vec<(usize, usize)>> = Vec::new();
+        for line in input.lines() {
+            let mut structure_vertices = Vec::new();
+            for vertex in line.split(" -> ") {
+                let [x, y] = scanf(input, vertex, "{},{}")?;
+                let x: usize = parse::token(input, x, "an x coordinate")?;
+                let y: usize = parse::token(input, y, "a y coordinate")?;
+                if let Some(&(previous_x, previous_y)) = structure_vertices.last() {
+                    if x != previous_x && y != previous_y {
+                        return Err(ParseError::at(
+                            input,
+                            vertex,
+                            "a point in line with the previous one",
+                        ));
+                    }
+                }
+                height = height.max(y + 1);
+                max_x = max_x.max(x);
+                structure_vertices.push((x, y));
+            }
+            vertices.push(structure_vertices);
+        }
+        if vertices.is_empty() {
+            return Err(ParseError::at(input, &input[input.len()..], "a rock path"));
+        }
+
+        if has_floor {
+            height += 2;
+        }
+        // sand moves at most one column per row, so it can't get further than `height` sideways.
+        let offset = height + 1;
+        let mut grid = Grid::new(max_x + 1 + 2 * offset, height, Element::Air);
+
+        for structure_vertices in vertices {
+            for window in structure_vertices.windows(2) {
+                for (x, y) in CoordinateRangeIterator::new(window[0], window[1]).unwrap() {
+                    grid[(x + offset, y)] = Element::Rock;
+                }
+            }
+        }
+
+        if has_floor {
+            grid.row_mut(height - 1).fill(Element::Rock);
+        }
+
+        Ok(Cave { grid, offset })
+    }
+
+    fn drop_sand(&mut self) -> bool {
+        let (mut x, mut y) = (SOURCE.0 + self.offset, SOURCE.1);
+
+        if let Element::Sand = self.grid[(x, y)] {
+            return false;
+        }
+
+        let y_max = self.grid.height() - 1;
+        let x_max = self.grid.width() - 1;
+        while y < y_max {
+            if let Element::Air = self.grid[(x, y + 1)] {
+                y += 1;
+            } else if x == 0 || x == x_max {
+                // there is no rock beyond the sides, so the sand falls into the abyss.
+                return false;
+            } else if let Element::Air = self.grid[(x - 1, y + 1)] {
+                x -= 1;
+                y += 1;
+            } else if let Element::Air = self.grid[(x + 1, y + 1)] {
+                x += 1;
+                y += 1;
+            } else {
+                self.grid[(x, y)] = Element::Sand;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_air = |x: usize| self.grid.column(x).all(|&item| item == Element::Air);
+        let front = (0..self.grid.width()).find(|&x| !is_air(x)).unwrap_or(0);
+        let back = (0..self.grid.width()).rfind(|&x| !is_air(x)).unwrap_or(0);
+
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for element in &row[front..=back] {
+                write!(f, "{}", element)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn solve(input: &str, has_floor: bool) -> Result<Option<u32>, ParseError> {
+    let mut cave = Cave::parse(input, has_floor)?;
+    let mut num_sand = 0;
+    while cave.drop_sand() {
+        num_sand += 1;
+    }
+    Ok(Some(num_sand))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, false)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    solve(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(24)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(93)));
+    }
+
+    #[test]
+    fn test_rocks_near_the_edges() {
+        let input = "0,2 -> 0,3\n1200,5 -> 1300,5\n";
+        assert_eq!(part_one(input), Ok(Some(0)));
+        assert_eq!(part_two(input), Ok(Some(7 * 7)));
+        // the pile on the floor gets wider than 1000 columns.
+        let input = "499,500 -> 501,500\n";
+        assert_eq!(part_one(input), Ok(Some(1)));
+        assert_eq!(part_two(input), Ok(Some(502 * 502 - 4)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("498,4 -> 498,6 -> 496,8\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 19));
+        assert_eq!(e.expected, "a point in line with the previous one");
+        let e = part_two("498,4 -> 498;6\n").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(1, 10, "a value followed by \",\"", "\"498;6\"")
+        );
+        let e = part_two("").unwrap_err();
+        assert_eq!(e, ParseError::new(1, 1, "a rock path", "nothing"));
+    }
+}
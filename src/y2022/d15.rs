@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::helpers::parsing::scanf;
+use crate::helpers::{IntervalSet, Point2};
+use crate::parse::{self, ParseError};
+
+struct Sensor {
+    position: Point2,
+    coverage_distance: usize,
+}
+
+impl Sensor {
+    fn new(position: Point2, nearest_beacon_position: Point2) -> Sensor {
+        let coverage_distance = position.manhattan(nearest_beacon_position);
+        Sensor {
+            position,
+            coverage_distance,
+        }
+    }
+
+    /// The positions in row `y` that are closer to the sensor than its beacon, which may be none.
+    fn coverage_interval(&self, y: isize) -> RangeInclusive<isize> {
+        let range = self.coverage_distance as isize - (self.position.y - y).abs();
+        self.position.x - range..=self.position.x + range
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Sensor>, HashSet<Point2>), ParseError> {
+    let mut sensors = Vec::new();
+    let mut beacon_positions = HashSet::new();
+
+    for line in input.lines() {
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = scanf(
+            input,
+            line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        )?;
+        let sensor_position = Point2::new(
+            parse::token(input, sensor_x, "an x coordinate")?,
+            parse::token(input, sensor_y, "a y coordinate")?,
+        );
+        let beacon_position = Point2::new(
+            parse::token(input, beacon_x, "an x coordinate")?,
+            parse::token(input, beacon_y, "a y coordinate")?,
+        );
+        sensors.push(Sensor::new(sensor_position, beacon_position));
+        beacon_positions.insert(beacon_position);
+    }
+
+    Ok((sensors, beacon_positions))
+}
+
+fn part_one_core(input: &str, y: isize) -> Result<Option<u32>, ParseError> {
+    let (sensors, beacons) = parse(input)?;
+    let beacons_in_scan: u32 = beacons
+        .iter()
+        .map(|position| (position.y == y) as u32)
+        .sum();
+
+    let coverage: IntervalSet<isize> = sensors
+        .iter()
+        .map(|sensor| sensor.coverage_interval(y))
+        .collect();
+
+    Ok(Some(coverage.total_len() as u32 - beacons_in_scan))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    part_one_core(input, 2_000_000)
+}
+
+pub fn part_two_core(input: &str, y_max: isize) -> Result<Option<u64>, ParseError> {
+    let (sensors, _) = parse(input)?;
+    let mut coverage = IntervalSet::new();
+    for y in 0..=y_max {
+        coverage.clear();
+        for sensor in &sensors {
+            coverage.insert(sensor.coverage_interval(y));
+        }
+        if let Some(gap) = coverage.gaps(0..=y_max).next() {
+            let x = *gap.start();
+            return Ok(Some(x as u64 * 4_000_000 + y as u64));
+        }
+    }
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseError> {
+    part_two_core(input, 4_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 15).unwrap();
+        assert_eq!(part_one_core(&input, 10), Ok(Some(26)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 15).unwrap();
+        assert_eq!(part_two_core(&input, 20), Ok(Some(56000011)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=1.5\n";
+        let e = part_one_core(input, 10).unwrap_err();
+        assert_eq!(e, ParseError::new(1, 51, "a y coordinate", "\"1.5\""));
+        let e = part_two_core("Sensor at x=2, y=18\n", 20).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 18));
+    }
+}
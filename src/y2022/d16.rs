@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use crate::helpers::parsing::scanf;
+use crate::helpers::search::bfs;
+use crate::parse::{self, ParseError};
+
+/// The valves worth opening, and the minutes it takes to walk between them.
+struct Network {
+    flow_rates: Vec<u32>,
+    /// `distances[i][j]` from valve `i` to valve `j`, with the starting valve after the others.
+    distances: Vec<Vec<u32>>,
+}
+
+impl Network {
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        let mut valves = HashMap::new();
+        let mut tunnels = Vec::new();
+        for line in input.lines() {
+            let [name, flow_rate, rest] = scanf(input, line, "Valve {} has flow rate={}; {}")?;
+            let Some(targets) = rest
+                .strip_prefix("tunnels lead to valves ")
+                .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+            else {
+                return Err(ParseError::at(input, rest, "'tunnels lead to valves ...'"));
+            };
+            let flow_rate: u32 = parse::token(input, flow_rate, "a flow rate")?;
+            valves.insert(name, (valves.len(), flow_rate));
+            tunnels.push(targets.split(", ").collect::<Vec<_>>());
+        }
+
+        let mut neighbours = Vec::with_capacity(tunnels.len());
+        for targets in tunnels {
+            let indices = targets
+                .into_iter()
+                .map(|target| match valves.get(target) {
+                    Some(&(index, _)) => Ok(index),
+                    None => Err(ParseError::at(input, target, "the name of a valve")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            neighbours.push(indices);
+        }
+        let Some(&(start, _)) = valves.get("AA") else {
+            return Err(ParseError::at(input, &input[input.len()..], "a valve 'AA'"));
+        };
+
+        let mut useful: Vec<(usize, u32)> = valves
+            .into_values()
+            .filter(|&(_, flow_rate)| flow_rate > 0)
+            .collect();
+        useful.sort();
+        let flow_rates = useful.iter().map(|&(_, flow_rate)| flow_rate).collect();
+        let nodes: Vec<usize> = useful
+            .iter()
+            .map(|&(index, _)| index)
+            .chain([start])
+            .collect();
+        let distances = nodes
+            .iter()
+            .map(|&from| {
+                nodes
+                    .iter()
+                    .map(|&to| {
+                        let successors = |&valve: &usize| neighbours[valve].clone();
+                        bfs(from, successors, |&valve| valve == to)
+                            .cost()
+                            .map_or(u32::MAX, |cost| cost as u32)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Network {
+            flow_rates,
+            distances,
+        })
+    }
+
+    /// Records in `best` the most pressure that can be released in the remaining `time` by opening
+    /// each set of valves, given as a bitmask.
+    fn explore(&self, valve: usize, time: u32, opened: usize, pressure: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(pressure);
+        for (next, &flow_rate) in self.flow_rates.iter().enumerate() {
+            let distance = self.distances[valve][next];
+            if opened & 1 << next != 0 || distance >= time {
+                continue;
+            }
+            let time = time - distance - 1;
+            let pressure = pressure + time * flow_rate;
+            self.explore(next, time, opened | 1 << next, pressure, best);
+        }
+    }
+
+    /// The most pressure released in `time` by opening each set of valves.
+    fn best_pressures(&self, time: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+        self.explore(self.flow_rates.len(), time, 0, 0, &mut best);
+        best
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let network = Network::parse(input)?;
+    Ok(network.best_pressures(30).into_iter().max())
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let network = Network::parse(input)?;
+    let best = network.best_pressures(26);
+
+    // the best pressure for any subset of each set, so the elephant's valves are the complement.
+    let mut best_subset = best.clone();
+    for bit in 0..network.flow_rates.len() {
+        for opened in 0..best_subset.len() {
+            if opened & 1 << bit != 0 {
+                best_subset[opened] = best_subset[opened].max(best_subset[opened ^ 1 << bit]);
+            }
+        }
+    }
+
+    let all = best.len() - 1;
+    Ok((0..best.len())
+        .map(|opened| best[opened] + best_subset[all ^ opened])
+        .max())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(1651)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(1707)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 16).unwrap();
+        let e = part_one(&input.replace("valves EE, GG", "valves EE, XX")).unwrap_err();
+        assert_eq!(e, ParseError::new(6, 54, "the name of a valve", "\"XX\""));
+        let e = part_two(&input.replace("rate=13", "rate=x")).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 24));
+    }
+}
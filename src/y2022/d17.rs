@@ -0,0 +1,172 @@
+use crate::helpers::cycle::Cycle;
+use crate::parse::ParseError;
+
+const WIDTH: usize = 7;
+
+/// The rocks in the order they fall, as rows from the bottom up in which bit 6 is the left wall.
+/// Each one starts two units away from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Clone, Copy)]
+enum Jet {
+    Left,
+    Right,
+}
+
+fn parse(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let pattern = input.trim_end();
+    if pattern.is_empty() {
+        return Err(ParseError::at(input, pattern, "a jet pattern"));
+    }
+    pattern
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::at(
+                input,
+                &pattern[i..i + c.len_utf8()],
+                "'<' or '>'",
+            )),
+        })
+        .collect()
+}
+
+struct Chamber<'a> {
+    jets: &'a [Jet],
+    next_jet: usize,
+    next_rock: usize,
+    rows: Vec<u8>,
+}
+
+impl Chamber<'_> {
+    fn new(jets: &[Jet]) -> Chamber<'_> {
+        Chamber {
+            jets,
+            next_jet: 0,
+            next_rock: 0,
+            rows: Vec::new(),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(i, row)| self.rows.get(y + i).is_some_and(|other| other & row != 0))
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.next_rock].to_vec();
+        self.next_rock = (self.next_rock + 1) % ROCKS.len();
+        let mut y = self.height() + 3;
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let pushed: Option<Vec<u8>> = match jet {
+                Jet::Left if rock.iter().all(|row| row & 1 << (WIDTH - 1) == 0) => {
+                    Some(rock.iter().map(|row| row << 1).collect())
+                }
+                Jet::Right if rock.iter().all(|row| row & 1 == 0) => {
+                    Some(rock.iter().map(|row| row >> 1).collect())
+                }
+                _ => None,
+            };
+            if let Some(pushed) = pushed.filter(|pushed| !self.collides(pushed, y)) {
+                rock = pushed;
+            }
+            if y == 0 || self.collides(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (i, row) in rock.into_iter().enumerate() {
+            match self.rows.get_mut(y + i) {
+                Some(other) => *other |= row,
+                None => self.rows.push(row),
+            }
+        }
+    }
+
+    /// What decides how the following rocks fall: the next rock and jet, and how far below the top
+    /// of the tower each column is filled.
+    fn state(&self) -> (usize, usize, [usize; WIDTH]) {
+        let mut depths = [0; WIDTH];
+        for (x, depth) in depths.iter_mut().enumerate() {
+            *depth = self
+                .rows
+                .iter()
+                .rev()
+                .position(|row| row & 1 << x != 0)
+                .unwrap_or(self.height());
+        }
+        (self.next_rock, self.next_jet, depths)
+    }
+}
+
+fn tower_height(input: &str, num_rocks: usize) -> Result<u64, ParseError> {
+    let jets = parse(input)?;
+    let mut chamber = Chamber::new(&jets);
+    let mut heights = Vec::new();
+    let states = std::iter::from_fn(|| {
+        heights.push(chamber.height());
+        let state = chamber.state();
+        chamber.drop_rock();
+        Some(state)
+    });
+    let states = states.take(num_rocks + 1);
+    let Some(cycle) = Cycle::detect(states) else {
+        return Ok(heights[num_rocks] as u64);
+    };
+
+    let growth = heights[cycle.start + cycle.len] - heights[cycle.start];
+    Ok((heights[cycle.equivalent(num_rocks)] + cycle.repeats(num_rocks) * growth) as u64)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>, ParseError> {
+    tower_height(input, 2022).map(Some)
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>, ParseError> {
+    tower_height(input, 1_000_000_000_000).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 17).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(3068)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 17).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(1514285714288)));
+    }
+
+    #[test]
+    fn test_short_tower() {
+        let input = crate::read_file("examples", 2022, 17).unwrap();
+        assert_eq!(tower_height(&input, 10), Ok(17));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one(">><x<\n").unwrap_err();
+        assert_eq!(e, ParseError::new(1, 4, "'<' or '>'", "\"x\""));
+        assert_eq!(part_two("\n").unwrap_err().expected, "a jet pattern");
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use crate::helpers::parsing::scanf;
+use crate::helpers::Point3;
+use crate::parse::{self, ParseError};
+
+fn parse(input: &str) -> Result<HashSet<Point3>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let [x, y, z] = scanf(input, line, "{},{},{}")?;
+            Ok(Point3::new(
+                parse::token(input, x, "an x coordinate")?,
+                parse::token(input, y, "a y coordinate")?,
+                parse::token(input, z, "a z coordinate")?,
+            ))
+        })
+        .collect()
+}
+
+/// Counts the faces of the cubes in `droplet` that touch a cube for which `is_outside` holds.
+fn surface_area(droplet: &HashSet<Point3>, is_outside: impl Fn(&Point3) -> bool) -> u32 {
+    droplet
+        .iter()
+        .flat_map(|cube| cube.neighbours_6())
+        .filter(is_outside)
+        .count() as u32
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let droplet = parse(input)?;
+    Ok(Some(surface_area(&droplet, |cube| !droplet.contains(cube))))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let droplet = parse(input)?;
+    let Some(&first) = droplet.iter().next() else {
+        return Ok(Some(0));
+    };
+
+    // flood fill the air around the droplet, within a box that leaves room to get around it.
+    let (min, max) = droplet.iter().fold((first, first), |(min, max), cube| {
+        (
+            Point3::new(min.x.min(cube.x), min.y.min(cube.y), min.z.min(cube.z)),
+            Point3::new(max.x.max(cube.x), max.y.max(cube.y), max.z.max(cube.z)),
+        )
+    });
+    let (min, max) = (min - Point3::new(1, 1, 1), max + Point3::new(1, 1, 1));
+    let in_bounds = |cube: &Point3| {
+        (min.x..=max.x).contains(&cube.x)
+            && (min.y..=max.y).contains(&cube.y)
+            && (min.z..=max.z).contains(&cube.z)
+    };
+
+    let mut outside = HashSet::from([min]);
+    let mut stack = vec![min];
+    while let Some(cube) = stack.pop() {
+        for neighbour in cube.neighbours_6() {
+            if in_bounds(&neighbour) && !droplet.contains(&neighbour) && outside.insert(neighbour) {
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    Ok(Some(surface_area(&droplet, |cube| outside.contains(cube))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 18).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(64)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 18).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(58)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("2,2,2\n1,2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = part_two("2,2,2\n1,2,z\n").unwrap_err();
+        assert_eq!(e, ParseError::new(2, 5, "a z coordinate", "\"z\""));
+    }
+}
@@ -1,5 +1,6 @@
-use regex::Regex;
-use std::{collections::HashSet, hash::Hash};
+use crate::helpers::parsing::ints;
+use crate::helpers::search::dfs_max;
+use crate::parse::ParseError;
 
 struct Blueprint {
     id: usize,
@@ -23,12 +24,12 @@ struct State {
 }
 
 impl Blueprint {
-    fn from_str(input: &str) -> Blueprint {
-        let pattern = Regex::new(r"\d+").unwrap();
-        let values: Vec<usize> = pattern
-            .find_iter(input)
-            .map(|m| m.as_str().parse::<usize>().unwrap())
-            .collect();
+    /// Parses the blueprint on `line` of `input`, which only needs its seven numbers in order.
+    fn parse(input: &str, line: &str) -> Result<Blueprint, ParseError> {
+        let values: Vec<usize> = ints(input, line)?;
+        if values.len() != 7 {
+            return Err(ParseError::at(input, line, "a blueprint with 7 numbers"));
+        }
 
         let id = values[0];
         let ore_robot_cost = values[1];
@@ -36,16 +37,16 @@ impl Blueprint {
         let obsidian_robot_cost = (values[3], values[4]);
         let geode_robot_cost = (values[5], values[6]);
 
-        Blueprint {
+        Ok(Blueprint {
             id,
             ore_robot_cost,
             clay_robot_cost,
             obsidian_robot_cost,
             geode_robot_cost,
-        }
+        })
     }
 
-    fn simulate(&self, minutes: usize, always_build: bool) -> usize {
+    fn simulate(&self, minutes: usize) -> usize {
         let max_ore_robots = [
             self.ore_robot_cost,
             self.clay_robot_cost,
@@ -58,7 +59,7 @@ impl Blueprint {
         let max_clay_robots = self.obsidian_robot_cost.1;
         let max_obsidian_robots = self.geode_robot_cost.1;
 
-        let mut states: Vec<State> = vec![State {
+        let start = State {
             time: 0,
             ore: 0,
             clay: 0,
@@ -68,20 +69,12 @@ impl Blueprint {
             clay_robots: 0,
             obsidian_robots: 0,
             geode_robots: 0,
-        }];
-        let mut seen: HashSet<State> = HashSet::new();
-        let mut max_geode = 0;
-
-        while !states.is_empty() {
-            let state = states.pop().unwrap();
+        };
 
+        let successors = |state: &State| {
+            let mut successors = Vec::new();
             if state.time == minutes {
-                max_geode = max_geode.max(state.geode);
-                continue;
-            }
-
-            if !seen.insert(state) {
-                continue;
+                return successors;
             }
 
             let time = state.time + 1;
@@ -91,104 +84,97 @@ impl Blueprint {
             let geode = state.geode + state.geode_robots;
 
             if state.ore >= self.geode_robot_cost.0 && state.obsidian >= self.geode_robot_cost.1 {
-                states.push(State {
+                successors.push(State {
                     time,
                     ore: ore - self.geode_robot_cost.0,
                     clay,
                     obsidian: obsidian - self.geode_robot_cost.1,
                     geode,
                     geode_robots: state.geode_robots + 1,
-                    ..state
+                    ..*state
                 });
-                continue;
+                return successors;
             }
 
-            let mut build = false;
-
-            if state.ore_robots < max_ore_robots && state.ore >= self.ore_robot_cost {
-                build = true;
-                states.push(State {
+            if state.obsidian_robots < max_obsidian_robots
+                && state.ore >= self.obsidian_robot_cost.0
+                && state.clay >= self.obsidian_robot_cost.1
+            {
+                successors.push(State {
                     time,
-                    ore: ore - self.ore_robot_cost,
-                    clay,
+                    ore: ore - self.obsidian_robot_cost.0,
+                    clay: clay - self.obsidian_robot_cost.1,
                     obsidian,
                     geode,
-                    ore_robots: state.ore_robots + 1,
-                    ..state
+                    obsidian_robots: state.obsidian_robots + 1,
+                    ..*state
                 })
             }
 
             if state.clay_robots < max_clay_robots && state.ore >= self.clay_robot_cost {
-                build = true;
-                states.push(State {
+                successors.push(State {
                     time,
                     ore: ore - self.clay_robot_cost,
                     clay,
                     obsidian,
                     geode,
                     clay_robots: state.clay_robots + 1,
-                    ..state
+                    ..*state
                 })
             }
 
-            if state.obsidian_robots < max_obsidian_robots
-                && state.ore >= self.obsidian_robot_cost.0
-                && state.clay >= self.obsidian_robot_cost.1
-            {
-                build = true;
-                states.push(State {
-                    time,
-                    ore: ore - self.obsidian_robot_cost.0,
-                    clay: clay - self.obsidian_robot_cost.1,
-                    obsidian,
-                    geode,
-                    obsidian_robots: state.obsidian_robots + 1,
-                    ..state
-                })
-            }
-
-            if !(always_build && build) {
-                states.push(State {
+            if state.ore_robots < max_ore_robots && state.ore >= self.ore_robot_cost {
+                successors.push(State {
                     time,
-                    ore,
+                    ore: ore - self.ore_robot_cost,
                     clay,
                     obsidian,
                     geode,
-                    ..state
-                });
+                    ore_robots: state.ore_robots + 1,
+                    ..*state
+                })
             }
-        }
 
-        max_geode
+            // waiting comes last so that building robots is explored first.
+            successors.push(State {
+                time,
+                ore,
+                clay,
+                obsidian,
+                geode,
+                ..*state
+            });
+            successors
+        };
+
+        // the geodes at the end if no other geode robot is built.
+        let score = |state: &State| state.geode + state.geode_robots * (minutes - state.time);
+
+        // upper bound: the current robots keep producing and a new geode robot is built every minute.
+        let bound = |state: &State| {
+            let remaining = minutes - state.time;
+            score(state) + remaining * remaining.saturating_sub(1) / 2
+        };
+
+        dfs_max(start, successors, score, bound).cost().unwrap()
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| {
-                let blueprint = Blueprint::from_str(line);
-                (blueprint.id * blueprint.simulate(24, false)) as u32
-            })
-            .sum(),
-    )
-}
-
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .take(3)
-            .map(|line| Blueprint::from_str(line).simulate(32, true) as u32)
-            .product(),
-    )
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let mut sum = 0;
+    for line in input.lines() {
+        let blueprint = Blueprint::parse(input, line)?;
+        sum += (blueprint.id * blueprint.simulate(24)) as u32;
+    }
+    Ok(Some(sum))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let mut product = 1;
+    for line in input.lines().take(3) {
+        product *= Blueprint::parse(input, line)?.simulate(32) as u32;
+    }
+    Ok(Some(product))
 }
 
 #[cfg(test)]
@@ -197,12 +183,20 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_one(&input), Some(33));
+        let input = crate::read_file("examples", 2022, 19).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(33)));
     }
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_two(&input), Some(3472));
+        let input = crate::read_file("examples", 2022, 19).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(3472)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 19).unwrap();
+        let e = part_one(&input.replacen("and 7 obsidian", "and obsidian", 1)).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.expected, "a blueprint with 7 numbers");
     }
 }
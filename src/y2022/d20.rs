@@ -0,0 +1,94 @@
+use crate::parse::{self, ParseError};
+
+fn decrypt(input: &str, key: i64, num_repeats: usize) -> Result<i64, ParseError> {
+    let mut indexed_mixed_file: Vec<(usize, i64)> = input
+        .lines()
+        .map(|line| Ok(parse::token::<i64>(input, line, "a number")? * key))
+        .enumerate()
+        .map(|(index, value)| value.map(|value| (index, value)))
+        .collect::<Result<_, ParseError>>()?;
+    if indexed_mixed_file.is_empty() {
+        return Err(ParseError::at(input, &input[input.len()..], "a number"));
+    }
+    let cycle = indexed_mixed_file.len();
+    // a single number has nowhere to move, so it stays at index 0.
+    let shift_cycle = (cycle - 1).max(1) as i64;
+    for indexed_shift in indexed_mixed_file
+        .clone()
+        .into_iter()
+        .cycle()
+        .take(cycle * num_repeats)
+    {
+        let index = indexed_mixed_file
+            .iter()
+            .position(|item| item == &indexed_shift)
+            .unwrap();
+        indexed_mixed_file.remove(index);
+        indexed_mixed_file.insert(
+            ((index as i64 + indexed_shift.1).rem_euclid(shift_cycle)) as usize,
+            indexed_shift,
+        );
+    }
+    let mixed_file: Vec<i64> = indexed_mixed_file
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect();
+    let Some(sentinel_index) = mixed_file.iter().position(|value| value == &0) else {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a 0 in the file",
+        ));
+    };
+    Ok([1_000, 2_000, 3_000]
+        .into_iter()
+        .map(|offset| {
+            let index = (sentinel_index + offset) % cycle;
+            mixed_file[index]
+        })
+        .sum())
+}
+
+pub fn part_one(input: &str) -> Result<Option<i64>, ParseError> {
+    decrypt(input, 1, 1).map(Some)
+}
+
+pub fn part_two(input: &str) -> Result<Option<i64>, ParseError> {
+    decrypt(input, 811_589_153, 10).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 20).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(3)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 20).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(1_623_178_306)));
+    }
+
+    #[test]
+    fn test_single_number() {
+        assert_eq!(part_one("0\n"), Ok(Some(0)));
+        assert_eq!(part_two("0\n"), Ok(Some(0)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("").unwrap_err();
+        assert_eq!(e, ParseError::new(1, 1, "a number", "nothing"));
+        let e = part_one("1\n2\n-3\nx\n").unwrap_err();
+        assert_eq!(e, ParseError::new(4, 1, "a number", "\"x\""));
+        let e = part_two("1\n2\n-3\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (4, 1, "a 0 in the file")
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use crate::helpers::parsing::key_value;
+use crate::parse::ParseError;
+
 #[derive(Hash, PartialEq, Eq)]
 enum Side {
     Left,
@@ -53,32 +56,43 @@ impl BinaryOperation {
     }
 }
 
-fn parse(
-    input: &str,
-) -> (
-    HashMap<&str, (&str, BinaryOperation, &str)>,
-    HashMap<&str, i64>,
-) {
+type Operations<'a> = HashMap<&'a str, (&'a str, BinaryOperation, &'a str)>;
+type Results<'a> = HashMap<&'a str, i64>;
+
+fn parse(input: &str) -> Result<(Operations<'_>, Results<'_>), ParseError> {
     let mut operations = HashMap::new();
     let mut results = HashMap::new();
+    let mut operands = Vec::new();
 
     for line in input.lines() {
-        let parts = line.split(": ").collect::<Vec<_>>();
-        debug_assert!(parts.len() == 2);
-        let id = parts[0];
-        let operation_or_result = parts[1];
+        let (id, operation_or_result) = key_value(input, line)?;
 
         if let Ok(result) = operation_or_result.parse::<i64>() {
             results.insert(id, result);
         } else {
             let parts = operation_or_result.split_whitespace().collect::<Vec<_>>();
-            debug_assert!(parts.len() == 3);
-            let operation = BinaryOperation::from_str(parts[1]).unwrap();
+            if parts.len() != 3 {
+                return Err(ParseError::at(
+                    input,
+                    operation_or_result,
+                    "a number or an operation",
+                ));
+            }
+            let Some(operation) = BinaryOperation::from_str(parts[1]) else {
+                return Err(ParseError::at(input, parts[1], "'+', '-', '*' or '/'"));
+            };
             operations.insert(id, (parts[0], operation, parts[2]));
+            operands.extend([parts[0], parts[2]]);
         }
     }
 
-    (operations, results)
+    for name in operands {
+        if !operations.contains_key(name) && !results.contains_key(name) {
+            return Err(ParseError::at(input, name, "a defined monkey"));
+        }
+    }
+
+    Ok((operations, results))
 }
 
 macro_rules! unwrap_or_continue {
@@ -92,17 +106,14 @@ macro_rules! unwrap_or_continue {
     };
 }
 
-fn compute<'a>(
-    operations: &mut HashMap<&'a str, (&'a str, BinaryOperation, &'a str)>,
-    results: &mut HashMap<&'a str, i64>,
-) {
+fn compute<'a>(operations: &mut Operations<'a>, results: &mut Results<'a>) {
     let mut performed = Vec::new();
     while !operations.is_empty() {
         for (result_id, (left_id, operation, right_id)) in operations.iter() {
             let left = unwrap_or_continue!(results.get(left_id));
             let right = unwrap_or_continue!(results.get(right_id));
             results.insert(result_id, operation.perform(*left, *right));
-            performed.push(result_id.clone());
+            performed.push(*result_id);
         }
 
         if performed.is_empty() {
@@ -119,7 +130,7 @@ fn compute<'a>(
 fn get_left_or_right<'a>(
     left_id: &'a str,
     right_id: &'a str,
-    results: &'a HashMap<&'a str, i64>,
+    results: &Results<'a>,
 ) -> Option<(&'a str, i64, Side)> {
     let other_id;
     let result;
@@ -142,49 +153,47 @@ fn query<'a>(
     mut id: &'a str,
     mut result: i64,
     target_id: &'a str,
-    operations: &mut HashMap<&'a str, (&'a str, BinaryOperation, &'a str)>,
-    results: &'a mut HashMap<&'a str, i64>,
+    operations: &mut Operations<'a>,
+    results: &Results<'a>,
 ) -> Option<i64> {
-    while !operations.is_empty() {
-        let (left_id, operation, right_id) = operations.remove(id).expect(id);
-        let (other_id, input, side) = get_left_or_right(left_id, right_id, results).unwrap();
+    while id != target_id {
+        let (left_id, operation, right_id) = operations.remove(id)?;
+        let (other_id, input, side) = get_left_or_right(left_id, right_id, results)?;
         id = other_id;
         result = operation.perform_inverse(result, input, side);
-        if id == target_id {
-            return Some(result);
-        }
     }
-    None
+    Some(result)
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
-    let (mut operations, mut results) = parse(input);
+pub fn part_one(input: &str) -> Result<Option<i64>, ParseError> {
+    let (mut operations, mut results) = parse(input)?;
     compute(&mut operations, &mut results);
-    results.get("root").copied()
+    Ok(results.get("root").copied())
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let (mut operations, mut results) = parse(input);
+pub fn part_two(input: &str) -> Result<Option<i64>, ParseError> {
+    let (mut operations, mut results) = parse(input)?;
 
-    let (left_id, _, right_id) = operations.remove("root").unwrap();
+    let Some((left_id, _, right_id)) = operations.remove("root") else {
+        return Ok(None);
+    };
 
     const TARGET_ID: &str = "humn";
     results.remove(TARGET_ID);
 
     compute(&mut operations, &mut results);
 
-    let (other_id, result, side) = get_left_or_right(left_id, right_id, &results).unwrap();
-    // let other_id = match side {
-    //     Side::Left => right_id,
-    //     Side::Right => left_id,
-    // };
-    query(other_id, result, "humn", &mut operations, &mut results)
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 21);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    // without exactly one side that doesn't depend on the human, there is no single answer.
+    let Some((other_id, result, _)) = get_left_or_right(left_id, right_id, &results) else {
+        return Ok(None);
+    };
+    Ok(query(
+        other_id,
+        result,
+        TARGET_ID,
+        &mut operations,
+        &results,
+    ))
 }
 
 #[cfg(test)]
@@ -193,13 +202,30 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 21);
-        assert_eq!(part_one(&input), Some(152));
+        let input = crate::read_file("examples", 2022, 21).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(152)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 21);
-        assert_eq!(part_two(&input), Some(301));
+        let input = crate::read_file("examples", 2022, 21).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(301)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("root: pppw % sjmn\n").unwrap_err();
+        assert_eq!(e, ParseError::new(1, 12, "'+', '-', '*' or '/'", "\"%\""));
+        let e = part_two("root: pppw + sjmn\nhumn 5\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = part_one("root: pppw + sjmn\npppw: 5\n").unwrap_err();
+        assert_eq!(e, ParseError::new(1, 14, "a defined monkey", "\"sjmn\""));
+    }
+
+    #[test]
+    fn test_human_on_both_sides() {
+        let input = "root: aaaa + bbbb\naaaa: humn * two\nbbbb: humn + two\nhumn: 5\ntwo: 2\n";
+        assert_eq!(part_one(input), Ok(Some(17)));
+        assert_eq!(part_two(input), Ok(None));
     }
 }
@@ -0,0 +1,331 @@
+use crate::helpers::parsing::blocks;
+use crate::helpers::{Dir4, Grid, Point2, Point3};
+use crate::parse::{self, ParseError};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+struct Board {
+    /// The map, padded with void to a rectangle.
+    map: Grid<Tile>,
+    path: Vec<Step>,
+    start: Point2,
+}
+
+impl Board {
+    fn parse(input: &str) -> Result<Board, ParseError> {
+        let mut blocks = blocks(input);
+        let (Some(map), Some(path)) = (blocks.next(), blocks.next()) else {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "a map and a path separated by a blank line",
+            ));
+        };
+
+        let width = map.lines().map(str::len).max().unwrap_or(0).max(1);
+        let mut cells = Vec::new();
+        for line in map.lines() {
+            for (i, c) in line.char_indices() {
+                cells.push(match c {
+                    ' ' => Tile::Void,
+                    '.' => Tile::Open,
+                    '#' => Tile::Wall,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            &line[i..i + c.len_utf8()],
+                            "' ', '.' or '#'",
+                        ))
+                    }
+                });
+            }
+            cells.resize(cells.len() + width - line.len(), Tile::Void);
+        }
+        let map = Grid::from_vec(width, cells);
+        let Some(x) = map.row(0).iter().position(|&tile| tile == Tile::Open) else {
+            return Err(ParseError::at(input, map_first_line(input), "an open tile"));
+        };
+
+        let mut steps = Vec::new();
+        for piece in path.split_inclusive(['L', 'R']) {
+            let (tiles, turn) = match piece.as_bytes().last() {
+                Some(b'L') => (&piece[..piece.len() - 1], Some(Step::Left)),
+                Some(b'R') => (&piece[..piece.len() - 1], Some(Step::Right)),
+                _ => (piece, None),
+            };
+            steps.push(Step::Forward(parse::token(
+                input,
+                tiles,
+                "a number of tiles",
+            )?));
+            steps.extend(turn);
+        }
+
+        Ok(Board {
+            map,
+            path: steps,
+            start: Point2::new(x as isize, 0),
+        })
+    }
+
+    fn tile(&self, position: Point2) -> Tile {
+        position
+            .try_into()
+            .ok()
+            .and_then(|position| self.map.get(position))
+            .copied()
+            .unwrap_or(Tile::Void)
+    }
+
+    /// Follows the path, letting `wrap` decide where a step off the edge of the map leads.
+    fn password(&self, wrap: impl Fn(Point2, Dir4) -> (Point2, Dir4)) -> isize {
+        let (mut position, mut facing) = (self.start, Dir4::East);
+        for step in &self.path {
+            match *step {
+                Step::Left => facing = facing.turn_left(),
+                Step::Right => facing = facing.turn_right(),
+                Step::Forward(tiles) => {
+                    for _ in 0..tiles {
+                        let (next, next_facing) = match self.tile(position + facing) {
+                            Tile::Void => wrap(position, facing),
+                            _ => (position + facing, facing),
+                        };
+                        if self.tile(next) == Tile::Wall {
+                            break;
+                        }
+                        (position, facing) = (next, next_facing);
+                    }
+                }
+            }
+        }
+        let facing = match facing {
+            Dir4::East => 0,
+            Dir4::South => 1,
+            Dir4::West => 2,
+            Dir4::North => 3,
+        };
+        1000 * (position.y + 1) + 4 * (position.x + 1) + facing
+    }
+}
+
+fn map_first_line(input: &str) -> &str {
+    input.lines().next().unwrap_or(input)
+}
+
+/// How a face of the net lies on the cube: its outward normal, and where its rightward and
+/// downward directions on the map point to.
+#[derive(Clone, Copy)]
+struct Face {
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    /// The direction in space in which `dir` on the map points.
+    fn towards(&self, dir: Dir4) -> Point3 {
+        match dir {
+            Dir4::North => -self.down,
+            Dir4::East => self.right,
+            Dir4::South => self.down,
+            Dir4::West => -self.right,
+        }
+    }
+
+    /// The face next to this one on the map in direction `dir`, folded over their shared edge.
+    fn fold(&self, dir: Dir4) -> Face {
+        let mut face = Face {
+            normal: self.towards(dir),
+            ..*self
+        };
+        match dir {
+            Dir4::North => face.down = self.normal,
+            Dir4::East => face.right = -self.normal,
+            Dir4::South => face.down = -self.normal,
+            Dir4::West => face.right = self.normal,
+        }
+        face
+    }
+}
+
+struct Cube {
+    size: isize,
+    /// The faces, with their positions on the map in units of `size`.
+    faces: Vec<(Point2, Face)>,
+}
+
+impl Cube {
+    /// Folds the map into a cube, if it is a net of one.
+    fn fold(board: &Board) -> Option<Cube> {
+        let area = board
+            .map
+            .iter()
+            .filter(|(_, &tile)| tile != Tile::Void)
+            .count();
+        let size = ((area / 6) as f64).sqrt() as isize;
+        if size == 0 || (size * size * 6) as usize != area {
+            return None;
+        }
+
+        let first = Face {
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        };
+        let mut faces = vec![(Point2::new(board.start.x / size, 0), first)];
+        let mut next = 0;
+        while let Some(&(position, face)) = faces.get(next) {
+            next += 1;
+            for dir in Dir4::ALL {
+                let neighbour = position + dir;
+                if board.tile(neighbour * size) != Tile::Void
+                    && faces.iter().all(|&(other, _)| other != neighbour)
+                {
+                    faces.push((neighbour, face.fold(dir)));
+                }
+            }
+        }
+
+        let on_faces = faces.iter().all(|&(position, _)| {
+            (0..size * size).all(|i| {
+                let offset = Point2::new(i % size, i / size);
+                board.tile(position * size + offset) != Tile::Void
+            })
+        });
+        let distinct = faces.iter().enumerate().all(|(i, (_, face))| {
+            faces[..i]
+                .iter()
+                .all(|(_, other)| other.normal != face.normal)
+        });
+        (faces.len() == 6 && on_faces && distinct).then_some(Cube { size, faces })
+    }
+
+    /// Where stepping off the edge of a face in direction `facing` leads, and the new facing.
+    fn wrap(&self, position: Point2, facing: Dir4) -> (Point2, Dir4) {
+        let size = self.size;
+        let corner = Point2::new(position.x / size, position.y / size);
+        let offset = position - corner * size;
+        let (_, face) = self
+            .faces
+            .iter()
+            .find(|&&(other, _)| other == corner)
+            .unwrap();
+        let (corner, next) = self
+            .faces
+            .iter()
+            .find(|(_, next)| next.normal == face.towards(facing))
+            .unwrap();
+
+        // walking over the edge goes down the side of the cube, into the next face.
+        let next_facing = Dir4::ALL
+            .into_iter()
+            .find(|&dir| next.towards(dir) == -face.normal)
+            .unwrap();
+
+        // the distance along the edge stays the same, but may be counted from the other end.
+        let (along, distance) = match facing {
+            Dir4::North | Dir4::South => (face.right, offset.x),
+            Dir4::East | Dir4::West => (face.down, offset.y),
+        };
+        let across = |axis: Point3| match axis == along {
+            true => distance,
+            false => size - 1 - distance,
+        };
+        let offset = match next_facing {
+            Dir4::North => Point2::new(across(next.right), size - 1),
+            Dir4::East => Point2::new(0, across(next.down)),
+            Dir4::South => Point2::new(across(next.right), 0),
+            Dir4::West => Point2::new(size - 1, across(next.down)),
+        };
+        (*corner * size + offset, next_facing)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<isize>, ParseError> {
+    let board = Board::parse(input)?;
+    Ok(Some(board.password(|mut position, facing| {
+        let back = facing.opposite();
+        while board.tile(position + back) != Tile::Void {
+            position += back;
+        }
+        (position, facing)
+    })))
+}
+
+pub fn part_two(input: &str) -> Result<Option<isize>, ParseError> {
+    let board = Board::parse(input)?;
+    let Some(cube) = Cube::fold(&board) else {
+        return Err(ParseError::at(
+            input,
+            map_first_line(input),
+            "a map that folds into a cube",
+        ));
+    };
+    Ok(Some(
+        board.password(|position, facing| cube.wrap(position, facing)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 22).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(6032)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 22).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(5031)));
+    }
+
+    #[test]
+    fn test_wrap_is_reversible() {
+        let example = crate::read_file("examples", 2022, 22).unwrap();
+        // the shape of the net in the puzzle inputs, which differs from the example.
+        let input = "  ....\n  ....\n  ..\n  ..\n....\n....\n..\n..\n\n1\n";
+        for input in [example.as_str(), input] {
+            let board = Board::parse(input).unwrap();
+            let cube = Cube::fold(&board).unwrap();
+            for (position, _) in board.map.iter() {
+                let position = Point2::from(position);
+                for facing in Dir4::ALL {
+                    if board.tile(position) == Tile::Void
+                        || board.tile(position + facing) != Tile::Void
+                    {
+                        continue;
+                    }
+                    let (next, next_facing) = cube.wrap(position, facing);
+                    assert!(board.tile(next) != Tile::Void);
+                    let back = cube.wrap(next, next_facing.opposite());
+                    assert_eq!(back, (position, facing.opposite()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 22).unwrap();
+        let e = part_one(&input.replace("R5L5", "R5X5")).unwrap_err();
+        assert_eq!(e, ParseError::new(14, 4, "a number of tiles", "\"5X5\""));
+        let e = part_one(&input.replace(".#..", ".*..")).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 10));
+        let e = part_two(&input.replace("        ...#....\n", "        ...#\n")).unwrap_err();
+        assert_eq!(e.expected, "a map that folds into a cube");
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::helpers::{Dir4, Dir8, Grid, Point2};
+use crate::parse::ParseError;
+
+/// How many empty rows and columns are added around the elves once they reach the edge of the
+/// grid.
+const MARGIN: usize = 8;
+
+/// The direction an elf considers together with the two diagonals next to it.
+fn directional_neighbours(position: Point2, direction: Dir4) -> [Point2; 3] {
+    let direction = Dir8::from(direction);
+    [
+        position + direction,
+        position + direction.turn_left(),
+        position + direction.turn_right(),
+    ]
+}
+
+fn elves(grid: &Grid<bool>) -> Vec<Point2> {
+    grid.iter()
+        .filter(|(_, &occupied)| occupied)
+        .map(|(position, _)| Point2::from(position))
+        .collect()
+}
+
+fn is_at_edge(grid: &Grid<bool>, position: Point2) -> bool {
+    position.x == 0
+        || position.y == 0
+        || position.x as usize == grid.width() - 1
+        || position.y as usize == grid.height() - 1
+}
+
+fn simulate(input: &str, max_rounds: usize) -> Result<(Grid<bool>, usize), ParseError> {
+    let mut grid = Grid::parse(input, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("'#' or '.'"),
+    })?;
+    if grid.iter().all(|(_, &occupied)| !occupied) {
+        return Err(ParseError::at(input, &input[input.len()..], "an elf ('#')"));
+    }
+    let mut proposed_positions: HashMap<Point2, Vec<Point2>> = HashMap::new();
+    let mut directions = VecDeque::from([Dir4::North, Dir4::South, Dir4::West, Dir4::East]);
+
+    let mut round = 0;
+    while round < max_rounds {
+        round += 1;
+
+        let mut elves = elves(&grid);
+        if elves.iter().any(|&position| is_at_edge(&grid, position)) {
+            grid = grid.padded(MARGIN, false);
+            let offset = Point2::new(MARGIN as isize, MARGIN as isize);
+            for position in &mut elves {
+                *position += offset;
+            }
+        }
+
+        for &position in &elves {
+            if position.neighbours_8().all(|position| !grid[position]) {
+                continue;
+            }
+            for &direction in &directions {
+                if directional_neighbours(position, direction)
+                    .iter()
+                    .all(|&position| !grid[position])
+                {
+                    proposed_positions
+                        .entry(position + direction)
+                        .or_default()
+                        .push(position);
+                    break;
+                }
+            }
+        }
+
+        if proposed_positions.is_empty() {
+            break;
+        }
+
+        for (new_position, old_positions) in &proposed_positions {
+            if old_positions.len() == 1 {
+                grid[old_positions[0]] = false;
+                grid[*new_position] = true;
+            }
+        }
+
+        proposed_positions.clear();
+        directions.rotate_left(1);
+    }
+    Ok((grid, round))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let (grid, _) = simulate(input, 10)?;
+    let elves = elves(&grid);
+
+    let mut min = Point2::new(isize::MAX, isize::MAX);
+    let mut max = Point2::new(isize::MIN, isize::MIN);
+    for position in &elves {
+        min = Point2::new(min.x.min(position.x), min.y.min(position.y));
+        max = Point2::new(max.x.max(position.x), max.y.max(position.y));
+    }
+
+    let width = (max.x - min.x + 1) as usize;
+    let height = (max.y - min.y + 1) as usize;
+    Ok(Some((width * height - elves.len()) as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, ParseError> {
+    let (_, rounds) = simulate(input, usize::MAX)?;
+    Ok(Some(rounds as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 23).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(110)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 23).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(20)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("....#..\n..###.#\n#..x#.#\n").unwrap_err();
+        assert_eq!(e, ParseError::new(3, 4, "'#' or '.'", "\"x\""));
+        let e = part_two("..#\n#.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        for input in ["", "...\n...\n"] {
+            let e = part_one(input).unwrap_err();
+            assert_eq!(
+                (e.expected.as_str(), e.found.as_str()),
+                ("an elf ('#')", "nothing")
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::helpers::search::bfs;
+use crate::helpers::{Dir4, Grid, Point2};
+use crate::parse::ParseError;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Ground,
+    Blizzard(Dir4),
+}
+
+struct Valley {
+    /// The blizzards inside the walls where they start, which is also where they are again
+    /// every `period` minutes.
+    basin: Grid<Option<Dir4>>,
+    period: usize,
+    /// The gaps in the top and bottom walls, relative to the basin.
+    entrance: Point2,
+    exit: Point2,
+}
+
+impl Valley {
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        let cells = Grid::parse(input, |c| match c {
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Ground),
+            '^' => Ok(Cell::Blizzard(Dir4::North)),
+            '>' => Ok(Cell::Blizzard(Dir4::East)),
+            'v' => Ok(Cell::Blizzard(Dir4::South)),
+            '<' => Ok(Cell::Blizzard(Dir4::West)),
+            _ => Err("'#', '.' or a blizzard like '>'"),
+        })?;
+        let (width, height) = (cells.width(), cells.height());
+        let cell_text = |(x, y)| &input.lines().nth(y).unwrap_or_default()[x..x + 1];
+        if width < 3 || height < 3 {
+            return Err(ParseError::at(input, input, "a valley surrounded by walls"));
+        }
+
+        let gap = |y| {
+            let gaps: Vec<usize> = (1..width - 1)
+                .filter(|&x| cells[(x, y)] == Cell::Ground)
+                .collect();
+            match gaps[..] {
+                [x] => Ok(Point2::new(x as isize - 1, y as isize - 1)),
+                _ => Err(ParseError::at(
+                    input,
+                    cell_text((0, y)),
+                    "a wall with one gap",
+                )),
+            }
+        };
+        let (entrance, exit) = (gap(0)?, gap(height - 1)?);
+
+        let mut basin = Grid::new(width - 2, height - 2, None);
+        for ((x, y), &cell) in cells.iter() {
+            let inside = (1..width - 1).contains(&x) && (1..height - 1).contains(&y);
+            let is_gap = [entrance, exit].contains(&Point2::new(x as isize - 1, y as isize - 1));
+            match cell {
+                Cell::Blizzard(dir) if inside => basin[(x - 1, y - 1)] = Some(dir),
+                Cell::Ground if inside => (),
+                Cell::Wall if !inside => (),
+                Cell::Ground if is_gap => (),
+                Cell::Wall => {
+                    let expected = "ground or a blizzard inside the walls";
+                    return Err(ParseError::at(input, cell_text((x, y)), expected));
+                }
+                _ => return Err(ParseError::at(input, cell_text((x, y)), "a wall")),
+            }
+        }
+
+        let (width, height) = (basin.width(), basin.height());
+        let period = width * height / gcd(width, height);
+        Ok(Valley {
+            basin,
+            period,
+            entrance,
+            exit,
+        })
+    }
+
+    fn is_inside(&self, position: Point2) -> bool {
+        (0..self.basin.width() as isize).contains(&position.x)
+            && (0..self.basin.height() as isize).contains(&position.y)
+    }
+
+    /// Whether no blizzard is at `position` inside the basin after `time` minutes.
+    fn is_clear(&self, position: Point2, time: usize) -> bool {
+        let (width, height) = (self.basin.width() as isize, self.basin.height() as isize);
+        Dir4::ALL.into_iter().all(|dir| {
+            let start = position - dir.offset() * (time % self.period) as isize;
+            let start = (
+                start.x.rem_euclid(width) as usize,
+                start.y.rem_euclid(height) as usize,
+            );
+            self.basin[start] != Some(dir)
+        })
+    }
+
+    /// The minute at which the fastest way from `from` to `to`, setting out at minute `start`,
+    /// arrives.
+    fn crossing(&self, from: Point2, to: Point2, start: usize) -> Option<usize> {
+        // the blizzards repeat, so the minute only matters up to the period.
+        let successors = |&(position, time): &(Point2, usize)| {
+            let time = (time + 1) % self.period;
+            [position]
+                .into_iter()
+                .chain(position.neighbours_4())
+                .filter(move |&next| {
+                    next == from || next == to || self.is_inside(next) && self.is_clear(next, time)
+                })
+                .map(move |next| (next, time))
+        };
+        let cost = bfs((from, start % self.period), successors, |&(position, _)| {
+            position == to
+        })
+        .cost()?;
+        Some(start + cost)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    let valley = Valley::parse(input)?;
+    Ok(valley.crossing(valley.entrance, valley.exit, 0))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>, ParseError> {
+    let valley = Valley::parse(input)?;
+    let (entrance, exit) = (valley.entrance, valley.exit);
+    Ok(valley
+        .crossing(entrance, exit, 0)
+        .and_then(|time| valley.crossing(exit, entrance, time))
+        .and_then(|time| valley.crossing(entrance, exit, time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 24).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(18)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 24).unwrap();
+        assert_eq!(part_two(&input), Ok(Some(54)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = crate::read_file("examples", 2022, 24).unwrap();
+        let e = part_one(&input.replace("#.<..<<#", "#.<.x<<#")).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 5));
+        let e = part_one(&input.replace("#>v.><>#", "#>v#><>#")).unwrap_err();
+        assert_eq!(e.expected, "ground or a blizzard inside the walls");
+        let e = part_two(&input.replace("######.#", "########")).unwrap_err();
+        assert_eq!(e, ParseError::new(6, 1, "a wall with one gap", "\"#\""));
+    }
+}
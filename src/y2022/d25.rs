@@ -0,0 +1,85 @@
+use crate::parse::ParseError;
+
+const DIGITS: [char; 5] = ['=', '-', '0', '1', '2'];
+
+/// Reads a SNAFU number, whose digits from '=' to '2' are worth -2 to 2 in base 5.
+fn from_snafu(input: &str, number: &str) -> Result<i64, ParseError> {
+    if number.is_empty() {
+        return Err(ParseError::at(input, number, "a SNAFU number"));
+    }
+    number.char_indices().try_fold(0, |value, (i, c)| {
+        match DIGITS.iter().position(|&digit| digit == c) {
+            Some(digit) => Ok(value * 5 + digit as i64 - 2),
+            None => Err(ParseError::at(
+                input,
+                &number[i..i + c.len_utf8()],
+                "a SNAFU digit from '=' to '2'",
+            )),
+        }
+    })
+}
+
+fn to_snafu(mut value: i64) -> String {
+    let mut digits = Vec::new();
+    while value != 0 || digits.is_empty() {
+        let digit = (value + 2).rem_euclid(5);
+        digits.push(DIGITS[digit as usize]);
+        value = (value - (digit - 2)) / 5;
+    }
+    digits.iter().rev().collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<String>, ParseError> {
+    let mut sum = 0;
+    for line in input.lines() {
+        sum += from_snafu(input, line)?;
+    }
+    Ok(Some(to_snafu(sum)))
+}
+
+/// The last day has no second puzzle.
+pub fn part_two(_input: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 25).unwrap();
+        assert_eq!(part_one(&input), Ok(Some(String::from("2=-1=0"))));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 25).unwrap();
+        assert_eq!(part_two(&input), None);
+    }
+
+    #[test]
+    fn test_snafu() {
+        let numbers = [
+            (0, "0"),
+            (3, "1="),
+            (8, "2="),
+            (2022, "1=11-2"),
+            (314159265, "1121-1110-1=0"),
+        ];
+        for (value, snafu) in numbers {
+            assert_eq!(to_snafu(value), snafu);
+            assert_eq!(from_snafu(snafu, snafu), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = part_one("1=-0-2\n12x11\n").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::new(2, 3, "a SNAFU digit from '=' to '2'", "\"x\"")
+        );
+        assert_eq!(part_one("1=\n\n2\n").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every solution in this directory with the runner.
+ */
+use crate::Day;
+
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;
+pub mod d16;
+pub mod d17;
+pub mod d18;
+pub mod d19;
+pub mod d20;
+pub mod d21;
+pub mod d22;
+pub mod d23;
+pub mod d24;
+pub mod d25;
+
+pub const DAYS: &[Day] = &[
+    day!(2022, 1, d01),
+    day!(2022, 2, d02),
+    day!(2022, 3, d03),
+    day!(2022, 4, d04),
+    day!(2022, 5, d05),
+    day!(2022, 6, d06),
+    day!(2022, 7, d07),
+    day!(2022, 8, d08),
+    day!(2022, 9, d09),
+    day!(2022, 10, d10),
+    day!(2022, 11, d11),
+    day!(2022, 12, d12),
+    day!(2022, 13, d13),
+    day!(2022, 14, d14),
+    day!(2022, 15, d15),
+    day!(2022, 16, d16),
+    day!(2022, 17, d17),
+    day!(2022, 18, d18),
+    day!(2022, 19, d19),
+    day!(2022, 20, d20),
+    day!(2022, 21, d21),
+    day!(2022, 22, d22),
+    day!(2022, 23, d23),
+    day!(2022, 24, d24),
+    day!(2022, 25, d25),
+];
@@ -0,0 +1,13 @@
+/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every year of solutions with the runner.
+ */
+use crate::Day;
+
+#[path = "y2022/mod.rs"]
+pub mod y2022;
+
+#[rustfmt::skip]
+pub const YEARS: &[(u16, &[Day])] = &[
+    (2022, y2022::DAYS),
+];
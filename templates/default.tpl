@@ -0,0 +1,24 @@
+pub fn part_one(input: &str) -> Option<{{ANSWER}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{ANSWER}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", {{YEAR}}, {{DAY}}).unwrap();
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", {{YEAR}}, {{DAY}}).unwrap();
+        assert_eq!(part_two(&input), None);
+    }
+}
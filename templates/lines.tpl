@@ -0,0 +1,32 @@
+use crate::parse::ParseError;
+
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{ANSWER}}>, ParseError> {
+    let _lines = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{ANSWER}}>, ParseError> {
+    let _lines = parse(input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", {{YEAR}}, {{DAY}}).unwrap();
+        assert_eq!(part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", {{YEAR}}, {{DAY}}).unwrap();
+        assert_eq!(part_two(&input), Ok(None));
+    }
+}